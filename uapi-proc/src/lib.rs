@@ -41,6 +41,12 @@ lazy_static! {
             tc.linux_5_6 = major > 5 || (major == 5 && minor >= 6);
             tc.linux_5_9 = major > 5 || (major == 5 && minor >= 9);
             tc.linux_5_10 = major > 5 || (major == 5 && minor >= 10);
+            tc.linux_5_12 = major > 5 || (major == 5 && minor >= 12);
+            tc.linux_5_13 = major > 5 || (major == 5 && minor >= 13);
+            tc.linux_5_15 = major > 5 || (major == 5 && minor >= 15);
+            tc.linux_5_16 = major > 5 || (major == 5 && minor >= 16);
+            tc.linux_6_7 = major > 6 || (major == 6 && minor >= 7);
+            tc.linux_6_8 = major > 6 || (major == 6 && minor >= 8);
         }
 
         tc
@@ -55,6 +61,12 @@ struct TestConditions {
     linux_5_6: bool,
     linux_5_9: bool,
     linux_5_10: bool,
+    linux_5_12: bool,
+    linux_5_13: bool,
+    linux_5_15: bool,
+    linux_5_16: bool,
+    linux_6_7: bool,
+    linux_6_8: bool,
 }
 
 impl Parse for TestConditions {
@@ -70,6 +82,12 @@ impl Parse for TestConditions {
                 "linux_5_6" => tc.linux_5_6 = true,
                 "linux_5_9" => tc.linux_5_9 = true,
                 "linux_5_10" => tc.linux_5_10 = true,
+                "linux_5_12" => tc.linux_5_12 = true,
+                "linux_5_13" => tc.linux_5_13 = true,
+                "linux_5_15" => tc.linux_5_15 = true,
+                "linux_5_16" => tc.linux_5_16 = true,
+                "linux_6_7" => tc.linux_6_7 = true,
+                "linux_6_8" => tc.linux_6_8 = true,
                 n => {
                     return Err(syn::Error::new(
                         name.span(),
@@ -97,7 +115,13 @@ pub fn test_if(
         || (tc.linux_5_2 && !TC.linux_5_2)
         || (tc.linux_5_6 && !TC.linux_5_6)
         || (tc.linux_5_9 && !TC.linux_5_9)
-        || (tc.linux_5_10 && !TC.linux_5_10);
+        || (tc.linux_5_10 && !TC.linux_5_10)
+        || (tc.linux_5_12 && !TC.linux_5_12)
+        || (tc.linux_5_13 && !TC.linux_5_13)
+        || (tc.linux_5_15 && !TC.linux_5_15)
+        || (tc.linux_5_16 && !TC.linux_5_16)
+        || (tc.linux_6_7 && !TC.linux_6_7)
+        || (tc.linux_6_8 && !TC.linux_6_8);
     #[allow(clippy::match_bool)] // already disabled upstream
     let ignore = match ignore {
         false => quote!(),
@@ -299,8 +323,8 @@ pub fn sock_opt(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
         ts.extend(quote!(
             #[man(#man)]
             #(#attr)*
-            pub fn #fnname(sockfd: c::c_int, mut val: #strct) -> Result<()> {
-                let mut len = mem::size_of::<#strct>() as _;
+            pub fn #fnname(sockfd: c::c_int, val: #strct) -> Result<()> {
+                let len = mem::size_of::<#strct>() as _;
                 let res = unsafe { c::setsockopt(sockfd, c::#level, c::#optname, &val as *const _ as *const _, len) };
                 map_err!(res).map(drop)
             }
@@ -14,7 +14,9 @@ use uapi::*;
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        mod netlink;
         mod strace;
+        pub use netlink::nlmsg_dump;
         pub use strace::strace;
     }
 }
@@ -0,0 +1,21 @@
+use uapi::*;
+
+/// Sends a `NLM_F_DUMP` request and calls `f` with the payload of every response
+pub fn nlmsg_dump<F: FnMut(&[u8])>(fd: c::c_int, msg: &[u8], mut f: F) {
+    send(fd, msg, 0).unwrap();
+    let mut buf = [0u8; 32 * 1024];
+    loop {
+        let reader = &*recv(fd, &mut buf[..], 0).unwrap();
+        for msg in nlmsg_iter(reader) {
+            let (header, payload) = msg.unwrap();
+            match header.nlmsg_type as c::c_int {
+                c::NLMSG_DONE => return,
+                c::NLMSG_ERROR => {
+                    let err = nlmsg_read_error(&header, payload).unwrap();
+                    panic!("{}", Errno(-err.error));
+                }
+                _ => f(payload),
+            }
+        }
+    }
+}
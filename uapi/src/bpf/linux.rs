@@ -0,0 +1,418 @@
+use crate::*;
+
+/// A jump target in a `BpfBuilder` program
+///
+/// Labels are created with `BpfBuilder::label` and bound to an instruction with
+/// `BpfBuilder::bind`. Only forward jumps are possible.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BpfLabel(usize);
+
+struct Fixup {
+    pc: usize,
+    jt: Option<BpfLabel>,
+    jf: Option<BpfLabel>,
+}
+
+/// A builder for classic BPF programs
+///
+/// This can be used to create programs for `SO_ATTACH_FILTER` and
+/// `SECCOMP_SET_MODE_FILTER`. Jumps refer to labels which are resolved by `finish`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut b = BpfBuilder::new();
+/// let drop = b.label();
+/// b.ld_abs(c::BPF_H, 12)
+///     .jeq(0x0800, None, Some(drop))
+///     .ret(!0)
+///     .bind(drop)
+///     .ret(0);
+/// let prog = b.finish()?;
+/// ```
+#[derive(Default)]
+pub struct BpfBuilder {
+    insns: Vec<c::sock_filter>,
+    labels: Vec<Option<usize>>,
+    fixups: Vec<Fixup>,
+    bad_label: bool,
+}
+
+impl BpfBuilder {
+    /// Creates a new, empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, unbound label
+    pub fn label(&mut self) -> BpfLabel {
+        self.labels.push(None);
+        BpfLabel(self.labels.len() - 1)
+    }
+
+    /// Binds a label to the next instruction
+    ///
+    /// Binding a label twice or binding a label of another builder causes `finish` to
+    /// fail.
+    pub fn bind(&mut self, label: BpfLabel) -> &mut Self {
+        match self.labels.get_mut(label.0) {
+            Some(pos) => {
+                self.bad_label |= pos.is_some();
+                *pos = Some(self.insns.len());
+            }
+            None => self.bad_label = true,
+        }
+        self
+    }
+
+    /// Appends a non-jump instruction
+    pub fn stmt(&mut self, code: u32, k: u32) -> &mut Self {
+        self.insns.push(c::sock_filter {
+            code: code as u16,
+            jt: 0,
+            jf: 0,
+            k,
+        });
+        self
+    }
+
+    /// Appends a conditional jump
+    ///
+    /// `code` must contain the `BPF_JMP` class. `None` continues with the next
+    /// instruction.
+    pub fn jump(
+        &mut self,
+        code: u32,
+        k: u32,
+        jt: Option<BpfLabel>,
+        jf: Option<BpfLabel>,
+    ) -> &mut Self {
+        self.fixups.push(Fixup {
+            pc: self.insns.len(),
+            jt,
+            jf,
+        });
+        self.stmt(code, k)
+    }
+
+    /// Appends `BPF_LD | size | BPF_ABS`
+    ///
+    /// `size` is one of `BPF_W`, `BPF_H`, and `BPF_B`.
+    pub fn ld_abs(&mut self, size: u32, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LD | size | c::BPF_ABS, k)
+    }
+
+    /// Appends `BPF_LD | size | BPF_IND`
+    pub fn ld_ind(&mut self, size: u32, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LD | size | c::BPF_IND, k)
+    }
+
+    /// Appends `BPF_LD | BPF_W | BPF_LEN`
+    pub fn ld_len(&mut self) -> &mut Self {
+        self.stmt(c::BPF_LD | c::BPF_W | c::BPF_LEN, 0)
+    }
+
+    /// Appends `BPF_LD | BPF_IMM`
+    pub fn ld_imm(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LD | c::BPF_IMM, k)
+    }
+
+    /// Appends `BPF_LD | BPF_MEM`
+    pub fn ld_mem(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LD | c::BPF_MEM, k)
+    }
+
+    /// Appends `BPF_LDX | BPF_W | BPF_IMM`
+    pub fn ldx_imm(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LDX | c::BPF_W | c::BPF_IMM, k)
+    }
+
+    /// Appends `BPF_LDX | BPF_W | BPF_MEM`
+    pub fn ldx_mem(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LDX | c::BPF_W | c::BPF_MEM, k)
+    }
+
+    /// Appends `BPF_LDX | BPF_B | BPF_MSH`
+    pub fn ldx_msh(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_LDX | c::BPF_B | c::BPF_MSH, k)
+    }
+
+    /// Appends `BPF_ST`
+    pub fn st(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_ST, k)
+    }
+
+    /// Appends `BPF_STX`
+    pub fn stx(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_STX, k)
+    }
+
+    /// Appends `BPF_ALU | op | BPF_K`
+    ///
+    /// `op` is one of `BPF_ADD`, `BPF_SUB`, `BPF_MUL`, `BPF_DIV`, `BPF_MOD`, `BPF_OR`,
+    /// `BPF_AND`, `BPF_XOR`, `BPF_LSH`, and `BPF_RSH`.
+    pub fn alu(&mut self, op: u32, k: u32) -> &mut Self {
+        self.stmt(c::BPF_ALU | op | c::BPF_K, k)
+    }
+
+    /// Appends `BPF_ALU | op | BPF_X`
+    pub fn alu_x(&mut self, op: u32) -> &mut Self {
+        self.stmt(c::BPF_ALU | op | c::BPF_X, 0)
+    }
+
+    /// Appends `BPF_ALU | BPF_NEG`
+    pub fn neg(&mut self) -> &mut Self {
+        self.stmt(c::BPF_ALU | c::BPF_NEG, 0)
+    }
+
+    /// Appends `BPF_MISC | BPF_TAX`
+    pub fn tax(&mut self) -> &mut Self {
+        self.stmt(c::BPF_MISC | c::BPF_TAX, 0)
+    }
+
+    /// Appends `BPF_MISC | BPF_TXA`
+    pub fn txa(&mut self) -> &mut Self {
+        self.stmt(c::BPF_MISC | c::BPF_TXA, 0)
+    }
+
+    /// Appends `BPF_JMP | BPF_JA`
+    pub fn ja(&mut self, target: BpfLabel) -> &mut Self {
+        self.jump(c::BPF_JMP | c::BPF_JA, 0, Some(target), None)
+    }
+
+    /// Appends `BPF_JMP | BPF_JEQ | BPF_K`
+    pub fn jeq(
+        &mut self,
+        k: u32,
+        jt: Option<BpfLabel>,
+        jf: Option<BpfLabel>,
+    ) -> &mut Self {
+        self.jump(c::BPF_JMP | c::BPF_JEQ | c::BPF_K, k, jt, jf)
+    }
+
+    /// Appends `BPF_JMP | BPF_JGT | BPF_K`
+    pub fn jgt(
+        &mut self,
+        k: u32,
+        jt: Option<BpfLabel>,
+        jf: Option<BpfLabel>,
+    ) -> &mut Self {
+        self.jump(c::BPF_JMP | c::BPF_JGT | c::BPF_K, k, jt, jf)
+    }
+
+    /// Appends `BPF_JMP | BPF_JGE | BPF_K`
+    pub fn jge(
+        &mut self,
+        k: u32,
+        jt: Option<BpfLabel>,
+        jf: Option<BpfLabel>,
+    ) -> &mut Self {
+        self.jump(c::BPF_JMP | c::BPF_JGE | c::BPF_K, k, jt, jf)
+    }
+
+    /// Appends `BPF_JMP | BPF_JSET | BPF_K`
+    pub fn jset(
+        &mut self,
+        k: u32,
+        jt: Option<BpfLabel>,
+        jf: Option<BpfLabel>,
+    ) -> &mut Self {
+        self.jump(c::BPF_JMP | c::BPF_JSET | c::BPF_K, k, jt, jf)
+    }
+
+    /// Appends `BPF_RET | BPF_K`
+    pub fn ret(&mut self, k: u32) -> &mut Self {
+        self.stmt(c::BPF_RET | c::BPF_K, k)
+    }
+
+    /// Appends `BPF_RET | BPF_A`
+    pub fn ret_a(&mut self) -> &mut Self {
+        self.stmt(c::BPF_RET | c::BPF_A, 0)
+    }
+
+    /// Resolves all jumps and returns the program
+    ///
+    /// Returns an error if a label is unbound, bound twice, or belongs to another
+    /// builder, if a jump does not fit
+    /// into its offset field, or if `bpf_verify` rejects the program.
+    pub fn finish(&self) -> Result<Vec<c::sock_filter>> {
+        if self.bad_label {
+            return einval();
+        }
+        let mut insns = self.insns.clone();
+        for fixup in &self.fixups {
+            let insn = &mut insns[fixup.pc];
+            if insn.code as u32 == c::BPF_JMP | c::BPF_JA {
+                insn.k = self.offset(fixup.pc, fixup.jt)?;
+            } else {
+                insn.jt = self.short_offset(fixup.pc, fixup.jt)?;
+                insn.jf = self.short_offset(fixup.pc, fixup.jf)?;
+            }
+        }
+        bpf_verify(&insns)?;
+        Ok(insns)
+    }
+
+    fn offset(&self, pc: usize, label: Option<BpfLabel>) -> Result<u32> {
+        let label = match label {
+            Some(l) => l,
+            _ => return Ok(0),
+        };
+        match self.labels.get(label.0) {
+            Some(&Some(target)) if target > pc => Ok((target - pc - 1) as u32),
+            _ => einval(),
+        }
+    }
+
+    fn short_offset(&self, pc: usize, label: Option<BpfLabel>) -> Result<u8> {
+        match self.offset(pc, label)? {
+            off if off <= u8::MAX as u32 => Ok(off as u8),
+            _ => einval(),
+        }
+    }
+}
+
+fn bpf_class(code: u16) -> u32 {
+    code as u32 & 0x07
+}
+
+fn bpf_code_allowed(code: u32) -> bool {
+    const ALU_OPS: [u32; 10] = [
+        c::BPF_ADD,
+        c::BPF_SUB,
+        c::BPF_MUL,
+        c::BPF_DIV,
+        c::BPF_MOD,
+        c::BPF_AND,
+        c::BPF_OR,
+        c::BPF_XOR,
+        c::BPF_LSH,
+        c::BPF_RSH,
+    ];
+    const JMP_OPS: [u32; 4] = [c::BPF_JEQ, c::BPF_JGE, c::BPF_JGT, c::BPF_JSET];
+    const LD: [u32; 9] = [
+        c::BPF_LD | c::BPF_W | c::BPF_ABS,
+        c::BPF_LD | c::BPF_H | c::BPF_ABS,
+        c::BPF_LD | c::BPF_B | c::BPF_ABS,
+        c::BPF_LD | c::BPF_W | c::BPF_LEN,
+        c::BPF_LD | c::BPF_W | c::BPF_IND,
+        c::BPF_LD | c::BPF_H | c::BPF_IND,
+        c::BPF_LD | c::BPF_B | c::BPF_IND,
+        c::BPF_LD | c::BPF_IMM,
+        c::BPF_LD | c::BPF_MEM,
+    ];
+    const LDX: [u32; 4] = [
+        c::BPF_LDX | c::BPF_W | c::BPF_LEN,
+        c::BPF_LDX | c::BPF_B | c::BPF_MSH,
+        c::BPF_LDX | c::BPF_IMM,
+        c::BPF_LDX | c::BPF_MEM,
+    ];
+
+    // the kernel's whitelist of classic BPF opcodes
+    match bpf_class(code as u16) {
+        _ if code > 0xff => false,
+        c::BPF_ALU => {
+            code == c::BPF_ALU | c::BPF_NEG
+                || (ALU_OPS.contains(&(code & 0xf0)) && code & 0x07 == c::BPF_ALU)
+        }
+        c::BPF_JMP => code == c::BPF_JMP | c::BPF_JA || JMP_OPS.contains(&(code & 0xf0)),
+        c::BPF_LD => LD.contains(&code),
+        c::BPF_LDX => LDX.contains(&code),
+        c::BPF_ST => code == c::BPF_ST,
+        c::BPF_STX => code == c::BPF_STX,
+        c::BPF_RET => code == c::BPF_RET | c::BPF_K || code == c::BPF_RET | c::BPF_A,
+        _ => code == c::BPF_MISC | c::BPF_TAX || code == c::BPF_MISC | c::BPF_TXA,
+    }
+}
+
+/// Checks a classic BPF program
+///
+/// This performs the same structural checks as the kernel:
+///
+/// - The program contains between 1 and `BPF_MAXINSNS` instructions.
+/// - All opcodes are known to the kernel.
+/// - All jumps stay within the program.
+/// - All accesses to scratch memory use an index smaller than `BPF_MEMWORDS`.
+/// - Scratch memory is stored to on all paths before it is loaded from.
+/// - There are no divisions by the constant 0.
+/// - The last instruction is a `BPF_RET`.
+///
+/// Returns `EINVAL` if any check fails.
+pub fn bpf_verify(prog: &[c::sock_filter]) -> Result<()> {
+    if prog.is_empty() || prog.len() > c::BPF_MAXINSNS as usize {
+        return einval();
+    }
+    for (pc, insn) in prog.iter().enumerate() {
+        let code = insn.code as u32;
+        let remaining = (prog.len() - pc - 1) as u64;
+        if !bpf_code_allowed(code) {
+            return einval();
+        }
+        let mem = insn.k >= c::BPF_MEMWORDS as u32;
+        let invalid = match bpf_class(insn.code) {
+            c::BPF_JMP if code == c::BPF_JMP | c::BPF_JA => insn.k as u64 >= remaining,
+            c::BPF_JMP => insn.jt as u64 >= remaining || insn.jf as u64 >= remaining,
+            c::BPF_ST | c::BPF_STX => mem,
+            c::BPF_LD | c::BPF_LDX => code & 0xe0 == c::BPF_MEM && mem,
+            c::BPF_ALU => {
+                let op = code & 0xf0;
+                (op == c::BPF_DIV || op == c::BPF_MOD)
+                    && code & c::BPF_X == c::BPF_K
+                    && insn.k == 0
+            }
+            _ => false,
+        };
+        if invalid {
+            return einval();
+        }
+    }
+    if bpf_class(prog[prog.len() - 1].code) != c::BPF_RET {
+        return einval();
+    }
+    bpf_check_loads(prog)
+}
+
+/// Checks that no scratch memory word is loaded before it has been stored to
+///
+/// This is the same conservative analysis as the kernel's `check_load_and_stores`.
+/// Jumps and scratch memory indices must already have been checked.
+fn bpf_check_loads(prog: &[c::sock_filter]) -> Result<()> {
+    // the scratch memory words that are valid on all paths to an instruction
+    let mut masks = [!0u16; c::BPF_MAXINSNS as usize];
+    let mut valid = 0u16;
+    for (pc, insn) in prog.iter().enumerate() {
+        valid &= masks[pc];
+        let code = insn.code as u32;
+        let bit = 1u16.wrapping_shl(insn.k);
+        match bpf_class(insn.code) {
+            c::BPF_ST | c::BPF_STX => valid |= bit,
+            c::BPF_LD | c::BPF_LDX if code & 0xe0 == c::BPF_MEM && valid & bit == 0 => {
+                return einval();
+            }
+            c::BPF_JMP if code == c::BPF_JMP | c::BPF_JA => {
+                masks[pc + 1 + insn.k as usize] &= valid;
+                valid = !0;
+            }
+            c::BPF_JMP => {
+                masks[pc + 1 + insn.jt as usize] &= valid;
+                masks[pc + 1 + insn.jf as usize] &= valid;
+                valid = !0;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Creates a `c::sock_fprog` that refers to `prog`
+///
+/// Returns an error if `prog` is longer than `u16::MAX` instructions.
+pub fn bpf_fprog(prog: &[c::sock_filter]) -> Result<c::sock_fprog> {
+    if prog.len() > u16::MAX as usize {
+        return einval();
+    }
+    Ok(c::sock_fprog {
+        len: prog.len() as _,
+        filter: prog.as_ptr() as *mut _,
+    })
+}
@@ -33,6 +33,8 @@ cfg_if! {
         pub const SYS_process_mrelease: c_long = 448;
         pub const SYS_futex_waitv: c_long = 449;
         pub const SYS_set_mempolicy_home_node: c_long = 450;
+        pub const SYS_statmount: c_long = 457;
+        pub const SYS_listmount: c_long = 458;
     }
 }
 
@@ -75,6 +77,19 @@ pub const MOUNT_ATTR_RELATIME: c_uint = 0x00000000;
 pub const MOUNT_ATTR_NOATIME: c_uint = 0x00000010;
 pub const MOUNT_ATTR_STRICTATIME: c_uint = 0x00000020;
 pub const MOUNT_ATTR_NODIRATIME: c_uint = 0x00000080;
+pub const MOUNT_ATTR_IDMAP: c_uint = 0x00100000;
+pub const MOUNT_ATTR_NOSYMFOLLOW: c_uint = 0x00200000;
+
+pub const MOUNT_ATTR_SIZE_VER0: usize = 32;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct mount_attr {
+    pub attr_set: u64,
+    pub attr_clr: u64,
+    pub propagation: u64,
+    pub userns_fd: u64,
+}
 
 pub const AT_RECURSIVE: c_uint = 0x8000;
 
@@ -138,6 +153,123 @@ pub unsafe fn fspick(dfd: c_int, path: *const c_char, flags: c_uint) -> c_int {
     syscall(SYS_fspick, dfd as usize, path as usize, flags as usize) as c_int
 }
 
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct mnt_id_req {
+    pub size: u32,
+    pub spare: u32,
+    pub mnt_id: u64,
+    pub param: u64,
+    pub mnt_ns_id: u64,
+}
+
+pub const MNT_ID_REQ_SIZE_VER0: u32 = 24;
+pub const MNT_ID_REQ_SIZE_VER1: u32 = 32;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct statmount {
+    pub size: u32,
+    pub mnt_opts: u32,
+    pub mask: u64,
+    pub sb_dev_major: u32,
+    pub sb_dev_minor: u32,
+    pub sb_magic: u64,
+    pub sb_flags: u32,
+    pub fs_type: u32,
+    pub mnt_id: u64,
+    pub mnt_parent_id: u64,
+    pub mnt_id_old: u32,
+    pub mnt_parent_id_old: u32,
+    pub mnt_attr: u64,
+    pub mnt_propagation: u64,
+    pub mnt_peer_group: u64,
+    pub mnt_master: u64,
+    pub propagate_from: u64,
+    pub mnt_root: u32,
+    pub mnt_point: u32,
+    pub mnt_ns_id: u64,
+    pub fs_subtype: u32,
+    pub sb_source: u32,
+    pub opt_num: u32,
+    pub opt_array: u32,
+    pub opt_sec_num: u32,
+    pub opt_sec_array: u32,
+    pub supported_mask: u64,
+    pub mnt_uidmap_num: u32,
+    pub mnt_uidmap: u32,
+    pub mnt_gidmap_num: u32,
+    pub mnt_gidmap: u32,
+    pub __spare2: [u64; 43],
+    // char str[];
+}
+
+pub const STATMOUNT_SB_BASIC: u64 = 0x00000001;
+pub const STATMOUNT_MNT_BASIC: u64 = 0x00000002;
+pub const STATMOUNT_PROPAGATE_FROM: u64 = 0x00000004;
+pub const STATMOUNT_MNT_ROOT: u64 = 0x00000008;
+pub const STATMOUNT_MNT_POINT: u64 = 0x00000010;
+pub const STATMOUNT_FS_TYPE: u64 = 0x00000020;
+pub const STATMOUNT_MNT_NS_ID: u64 = 0x00000040;
+pub const STATMOUNT_MNT_OPTS: u64 = 0x00000080;
+pub const STATMOUNT_FS_SUBTYPE: u64 = 0x00000100;
+pub const STATMOUNT_SB_SOURCE: u64 = 0x00000200;
+pub const STATMOUNT_OPT_ARRAY: u64 = 0x00000400;
+pub const STATMOUNT_OPT_SEC_ARRAY: u64 = 0x00000800;
+pub const STATMOUNT_SUPPORTED_MASK: u64 = 0x00001000;
+pub const STATMOUNT_MNT_UIDMAP: u64 = 0x00002000;
+pub const STATMOUNT_MNT_GIDMAP: u64 = 0x00004000;
+
+pub const LSMT_ROOT: u64 = 0xffffffffffffffff;
+pub const LISTMOUNT_REVERSE: c_uint = 1 << 0;
+
+pub unsafe fn statmount(
+    req: *const mnt_id_req,
+    buf: *mut statmount,
+    bufsize: usize,
+    flags: c_uint,
+) -> c_int {
+    syscall(
+        SYS_statmount,
+        req as usize,
+        buf as usize,
+        bufsize,
+        flags as usize,
+    ) as c_int
+}
+
+pub unsafe fn listmount(
+    req: *const mnt_id_req,
+    mnt_ids: *mut u64,
+    nr_mnt_ids: usize,
+    flags: c_uint,
+) -> c_int {
+    syscall(
+        SYS_listmount,
+        req as usize,
+        mnt_ids as usize,
+        nr_mnt_ids,
+        flags as usize,
+    ) as c_int
+}
+
+pub unsafe fn mount_setattr(
+    dfd: c_int,
+    path: *const c_char,
+    flags: c_uint,
+    attr: *mut mount_attr,
+    size: usize,
+) -> c_int {
+    syscall(
+        SYS_mount_setattr,
+        dfd as usize,
+        path as usize,
+        flags as usize,
+        attr as usize,
+        size,
+    ) as c_int
+}
+
 pub const UINPUT_MAX_NAME_SIZE: usize = 80;
 
 #[repr(C)]
@@ -305,3 +437,980 @@ pub const CLOSE_RANGE_UNSHARE: c::c_uint = 1 << 1;
 pub const CLOSE_RANGE_CLOEXEC: c::c_uint = 1 << 2;
 
 pub const PIDFD_NONBLOCK: c::c_uint = c::O_NONBLOCK as _;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct io_sqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct io_cqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct io_uring_params {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    pub sq_thread_idle: u32,
+    pub features: u32,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: io_sqring_offsets,
+    pub cq_off: io_cqring_offsets,
+}
+
+/// The anonymous unions of the kernel structure are represented by their first member
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct io_uring_sqe {
+    pub opcode: u8,
+    pub flags: u8,
+    pub ioprio: u16,
+    pub fd: i32,
+    /// union { off, addr2, { cmd_op, __pad1 } }
+    pub off: u64,
+    /// union { addr, splice_off_in, { level, optname } }
+    pub addr: u64,
+    pub len: u32,
+    /// union { rw_flags, fsync_flags, poll32_events, timeout_flags, ... }
+    pub rw_flags: u32,
+    pub user_data: u64,
+    /// union { buf_index, buf_group }
+    pub buf_index: u16,
+    pub personality: u16,
+    /// union { splice_fd_in, file_index, optlen, { addr_len, __pad3 } }
+    pub splice_fd_in: i32,
+    /// union { addr3, optval, cmd }
+    pub addr3: u64,
+    pub __pad2: [u64; 1],
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct io_uring_cqe {
+    pub user_data: u64,
+    pub res: i32,
+    pub flags: u32,
+}
+
+pub const IORING_SETUP_IOPOLL: u32 = 1 << 0;
+pub const IORING_SETUP_SQPOLL: u32 = 1 << 1;
+pub const IORING_SETUP_SQ_AFF: u32 = 1 << 2;
+pub const IORING_SETUP_CQSIZE: u32 = 1 << 3;
+pub const IORING_SETUP_CLAMP: u32 = 1 << 4;
+pub const IORING_SETUP_ATTACH_WQ: u32 = 1 << 5;
+pub const IORING_SETUP_R_DISABLED: u32 = 1 << 6;
+pub const IORING_SETUP_SUBMIT_ALL: u32 = 1 << 7;
+pub const IORING_SETUP_COOP_TASKRUN: u32 = 1 << 8;
+pub const IORING_SETUP_TASKRUN_FLAG: u32 = 1 << 9;
+pub const IORING_SETUP_SQE128: u32 = 1 << 10;
+pub const IORING_SETUP_CQE32: u32 = 1 << 11;
+pub const IORING_SETUP_SINGLE_ISSUER: u32 = 1 << 12;
+pub const IORING_SETUP_DEFER_TASKRUN: u32 = 1 << 13;
+pub const IORING_SETUP_NO_MMAP: u32 = 1 << 14;
+pub const IORING_SETUP_REGISTERED_FD_ONLY: u32 = 1 << 15;
+pub const IORING_SETUP_NO_SQARRAY: u32 = 1 << 16;
+
+pub const IORING_OP_NOP: u8 = 0;
+pub const IORING_OP_READV: u8 = 1;
+pub const IORING_OP_WRITEV: u8 = 2;
+pub const IORING_OP_FSYNC: u8 = 3;
+pub const IORING_OP_READ_FIXED: u8 = 4;
+pub const IORING_OP_WRITE_FIXED: u8 = 5;
+pub const IORING_OP_POLL_ADD: u8 = 6;
+pub const IORING_OP_POLL_REMOVE: u8 = 7;
+pub const IORING_OP_SYNC_FILE_RANGE: u8 = 8;
+pub const IORING_OP_SENDMSG: u8 = 9;
+pub const IORING_OP_RECVMSG: u8 = 10;
+pub const IORING_OP_TIMEOUT: u8 = 11;
+pub const IORING_OP_TIMEOUT_REMOVE: u8 = 12;
+pub const IORING_OP_ACCEPT: u8 = 13;
+pub const IORING_OP_ASYNC_CANCEL: u8 = 14;
+pub const IORING_OP_LINK_TIMEOUT: u8 = 15;
+pub const IORING_OP_CONNECT: u8 = 16;
+pub const IORING_OP_FALLOCATE: u8 = 17;
+pub const IORING_OP_OPENAT: u8 = 18;
+pub const IORING_OP_CLOSE: u8 = 19;
+pub const IORING_OP_FILES_UPDATE: u8 = 20;
+pub const IORING_OP_STATX: u8 = 21;
+pub const IORING_OP_READ: u8 = 22;
+pub const IORING_OP_WRITE: u8 = 23;
+pub const IORING_OP_FADVISE: u8 = 24;
+pub const IORING_OP_MADVISE: u8 = 25;
+pub const IORING_OP_SEND: u8 = 26;
+pub const IORING_OP_RECV: u8 = 27;
+pub const IORING_OP_OPENAT2: u8 = 28;
+pub const IORING_OP_EPOLL_CTL: u8 = 29;
+pub const IORING_OP_SPLICE: u8 = 30;
+pub const IORING_OP_PROVIDE_BUFFERS: u8 = 31;
+pub const IORING_OP_REMOVE_BUFFERS: u8 = 32;
+pub const IORING_OP_TEE: u8 = 33;
+pub const IORING_OP_SHUTDOWN: u8 = 34;
+pub const IORING_OP_RENAMEAT: u8 = 35;
+pub const IORING_OP_UNLINKAT: u8 = 36;
+pub const IORING_OP_MKDIRAT: u8 = 37;
+pub const IORING_OP_SYMLINKAT: u8 = 38;
+pub const IORING_OP_LINKAT: u8 = 39;
+pub const IORING_OP_MSG_RING: u8 = 40;
+pub const IORING_OP_FSETXATTR: u8 = 41;
+pub const IORING_OP_SETXATTR: u8 = 42;
+pub const IORING_OP_FGETXATTR: u8 = 43;
+pub const IORING_OP_GETXATTR: u8 = 44;
+pub const IORING_OP_SOCKET: u8 = 45;
+pub const IORING_OP_URING_CMD: u8 = 46;
+pub const IORING_OP_SEND_ZC: u8 = 47;
+pub const IORING_OP_SENDMSG_ZC: u8 = 48;
+
+pub const IOSQE_FIXED_FILE: u8 = 1 << 0;
+pub const IOSQE_IO_DRAIN: u8 = 1 << 1;
+pub const IOSQE_IO_LINK: u8 = 1 << 2;
+pub const IOSQE_IO_HARDLINK: u8 = 1 << 3;
+pub const IOSQE_ASYNC: u8 = 1 << 4;
+pub const IOSQE_BUFFER_SELECT: u8 = 1 << 5;
+pub const IOSQE_CQE_SKIP_SUCCESS: u8 = 1 << 6;
+
+pub const IORING_FSYNC_DATASYNC: u32 = 1 << 0;
+
+pub const IORING_TIMEOUT_ABS: u32 = 1 << 0;
+
+pub const IORING_CQE_F_BUFFER: u32 = 1 << 0;
+pub const IORING_CQE_F_MORE: u32 = 1 << 1;
+pub const IORING_CQE_F_SOCK_NONEMPTY: u32 = 1 << 2;
+pub const IORING_CQE_F_NOTIF: u32 = 1 << 3;
+pub const IORING_CQE_BUFFER_SHIFT: u32 = 16;
+
+pub const IORING_OFF_SQ_RING: c::off_t = 0;
+pub const IORING_OFF_CQ_RING: c::off_t = 0x8000000;
+pub const IORING_OFF_SQES: c::off_t = 0x10000000;
+
+pub const IORING_SQ_NEED_WAKEUP: u32 = 1 << 0;
+pub const IORING_SQ_CQ_OVERFLOW: u32 = 1 << 1;
+pub const IORING_SQ_TASKRUN: u32 = 1 << 2;
+
+pub const IORING_CQ_EVENTFD_DISABLED: u32 = 1 << 0;
+
+pub const IORING_ENTER_GETEVENTS: u32 = 1 << 0;
+pub const IORING_ENTER_SQ_WAKEUP: u32 = 1 << 1;
+pub const IORING_ENTER_SQ_WAIT: u32 = 1 << 2;
+pub const IORING_ENTER_EXT_ARG: u32 = 1 << 3;
+pub const IORING_ENTER_REGISTERED_RING: u32 = 1 << 4;
+
+pub const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+pub const IORING_FEAT_NODROP: u32 = 1 << 1;
+pub const IORING_FEAT_SUBMIT_STABLE: u32 = 1 << 2;
+pub const IORING_FEAT_RW_CUR_POS: u32 = 1 << 3;
+pub const IORING_FEAT_CUR_PERSONALITY: u32 = 1 << 4;
+pub const IORING_FEAT_FAST_POLL: u32 = 1 << 5;
+pub const IORING_FEAT_POLL_32BITS: u32 = 1 << 6;
+pub const IORING_FEAT_SQPOLL_NONFIXED: u32 = 1 << 7;
+pub const IORING_FEAT_EXT_ARG: u32 = 1 << 8;
+pub const IORING_FEAT_NATIVE_WORKERS: u32 = 1 << 9;
+pub const IORING_FEAT_RSRC_TAGS: u32 = 1 << 10;
+pub const IORING_FEAT_CQE_SKIP: u32 = 1 << 11;
+pub const IORING_FEAT_LINKED_FILE: u32 = 1 << 12;
+
+pub const IORING_REGISTER_BUFFERS: c_uint = 0;
+pub const IORING_UNREGISTER_BUFFERS: c_uint = 1;
+pub const IORING_REGISTER_FILES: c_uint = 2;
+pub const IORING_UNREGISTER_FILES: c_uint = 3;
+pub const IORING_REGISTER_EVENTFD: c_uint = 4;
+pub const IORING_UNREGISTER_EVENTFD: c_uint = 5;
+pub const IORING_REGISTER_FILES_UPDATE: c_uint = 6;
+pub const IORING_REGISTER_EVENTFD_ASYNC: c_uint = 7;
+pub const IORING_REGISTER_PROBE: c_uint = 8;
+pub const IORING_REGISTER_PERSONALITY: c_uint = 9;
+pub const IORING_UNREGISTER_PERSONALITY: c_uint = 10;
+pub const IORING_REGISTER_RESTRICTIONS: c_uint = 11;
+pub const IORING_REGISTER_ENABLE_RINGS: c_uint = 12;
+
+pub unsafe fn io_uring_setup(entries: u32, p: *mut io_uring_params) -> c_int {
+    syscall(SYS_io_uring_setup, entries as usize, p as usize) as c_int
+}
+
+pub unsafe fn io_uring_enter(
+    fd: c_int,
+    to_submit: c_uint,
+    min_complete: c_uint,
+    flags: c_uint,
+    arg: *const c_void,
+    argsz: usize,
+) -> c_int {
+    syscall(
+        SYS_io_uring_enter,
+        fd as usize,
+        to_submit as usize,
+        min_complete as usize,
+        flags as usize,
+        arg as usize,
+        argsz,
+    ) as c_int
+}
+
+pub unsafe fn io_uring_register(
+    fd: c_int,
+    opcode: c_uint,
+    arg: *mut c_void,
+    nr_args: c_uint,
+) -> c_int {
+    syscall(
+        SYS_io_uring_register,
+        fd as usize,
+        opcode as usize,
+        arg as usize,
+        nr_args as usize,
+    ) as c_int
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct landlock_ruleset_attr {
+    pub handled_access_fs: u64,
+    pub handled_access_net: u64,
+    pub scoped: u64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C, packed)]
+pub struct landlock_path_beneath_attr {
+    pub allowed_access: u64,
+    pub parent_fd: i32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct landlock_net_port_attr {
+    pub allowed_access: u64,
+    pub port: u64,
+}
+
+pub const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+pub const LANDLOCK_RULE_PATH_BENEATH: c_uint = 1;
+pub const LANDLOCK_RULE_NET_PORT: c_uint = 2;
+
+pub const LANDLOCK_ACCESS_FS_EXECUTE: u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+pub const LANDLOCK_ACCESS_FS_READ_FILE: u64 = 1 << 2;
+pub const LANDLOCK_ACCESS_FS_READ_DIR: u64 = 1 << 3;
+pub const LANDLOCK_ACCESS_FS_REMOVE_DIR: u64 = 1 << 4;
+pub const LANDLOCK_ACCESS_FS_REMOVE_FILE: u64 = 1 << 5;
+pub const LANDLOCK_ACCESS_FS_MAKE_CHAR: u64 = 1 << 6;
+pub const LANDLOCK_ACCESS_FS_MAKE_DIR: u64 = 1 << 7;
+pub const LANDLOCK_ACCESS_FS_MAKE_REG: u64 = 1 << 8;
+pub const LANDLOCK_ACCESS_FS_MAKE_SOCK: u64 = 1 << 9;
+pub const LANDLOCK_ACCESS_FS_MAKE_FIFO: u64 = 1 << 10;
+pub const LANDLOCK_ACCESS_FS_MAKE_BLOCK: u64 = 1 << 11;
+pub const LANDLOCK_ACCESS_FS_MAKE_SYM: u64 = 1 << 12;
+pub const LANDLOCK_ACCESS_FS_REFER: u64 = 1 << 13;
+pub const LANDLOCK_ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+pub const LANDLOCK_ACCESS_FS_IOCTL_DEV: u64 = 1 << 15;
+
+pub const LANDLOCK_ACCESS_NET_BIND_TCP: u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_NET_CONNECT_TCP: u64 = 1 << 1;
+
+pub const LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET: u64 = 1 << 0;
+pub const LANDLOCK_SCOPE_SIGNAL: u64 = 1 << 1;
+
+pub unsafe fn landlock_create_ruleset(
+    attr: *const landlock_ruleset_attr,
+    size: usize,
+    flags: u32,
+) -> c_int {
+    syscall(
+        SYS_landlock_create_ruleset,
+        attr as usize,
+        size,
+        flags as usize,
+    ) as c_int
+}
+
+pub unsafe fn landlock_add_rule(
+    ruleset_fd: c_int,
+    rule_type: c_uint,
+    rule_attr: *const c_void,
+    flags: u32,
+) -> c_int {
+    syscall(
+        SYS_landlock_add_rule,
+        ruleset_fd as usize,
+        rule_type as usize,
+        rule_attr as usize,
+        flags as usize,
+    ) as c_int
+}
+
+pub unsafe fn landlock_restrict_self(ruleset_fd: c_int, flags: u32) -> c_int {
+    syscall(
+        SYS_landlock_restrict_self,
+        ruleset_fd as usize,
+        flags as usize,
+    ) as c_int
+}
+
+#[derive(Copy, Clone)]
+#[repr(C, align(8))]
+pub struct clone_args {
+    pub flags: u64,
+    pub pidfd: u64,
+    pub child_tid: u64,
+    pub parent_tid: u64,
+    pub exit_signal: u64,
+    pub stack: u64,
+    pub stack_size: u64,
+    pub tls: u64,
+    pub set_tid: u64,
+    pub set_tid_size: u64,
+    pub cgroup: u64,
+}
+
+pub const CLONE_CLEAR_SIGHAND: u64 = 0x100000000;
+pub const CLONE_INTO_CGROUP: u64 = 0x200000000;
+
+pub unsafe fn clone3(cl_args: *mut clone_args, size: usize) -> c_int {
+    syscall(SYS_clone3, cl_args as usize, size) as c_int
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct __kernel_timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct futex_waitv {
+    pub val: u64,
+    pub uaddr: u64,
+    pub flags: u32,
+    pub __reserved: u32,
+}
+
+pub const FUTEX_WAITERS: u32 = 0x80000000;
+pub const FUTEX_OWNER_DIED: u32 = 0x40000000;
+pub const FUTEX_TID_MASK: u32 = 0x3fffffff;
+
+pub const FUTEX_32: u32 = 2;
+pub const FUTEX_WAITV_MAX: u32 = 128;
+
+pub const FUTEX2_SIZE_U8: u32 = 0x00;
+pub const FUTEX2_SIZE_U16: u32 = 0x01;
+pub const FUTEX2_SIZE_U32: u32 = 0x02;
+pub const FUTEX2_SIZE_U64: u32 = 0x03;
+pub const FUTEX2_NUMA: u32 = 0x04;
+pub const FUTEX2_PRIVATE: u32 = 128;
+
+pub unsafe fn futex(
+    uaddr: *mut u32,
+    futex_op: c_int,
+    val: u32,
+    timeout: *const timespec,
+    uaddr2: *mut u32,
+    val3: u32,
+) -> c_int {
+    syscall(
+        SYS_futex,
+        uaddr as usize,
+        futex_op as usize,
+        val as usize,
+        timeout as usize,
+        uaddr2 as usize,
+        val3 as usize,
+    ) as c_int
+}
+
+pub unsafe fn futex_waitv(
+    waiters: *mut futex_waitv,
+    nr_futexes: c_uint,
+    flags: c_uint,
+    timeout: *const __kernel_timespec,
+    clockid: clockid_t,
+) -> c_int {
+    syscall(
+        SYS_futex_waitv,
+        waiters as usize,
+        nr_futexes as usize,
+        flags as usize,
+        timeout as usize,
+        clockid as usize,
+    ) as c_int
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct statx_timestamp {
+    pub tv_sec: i64,
+    pub tv_nsec: u32,
+    pub __reserved: i32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct statx {
+    pub stx_mask: u32,
+    pub stx_blksize: u32,
+    pub stx_attributes: u64,
+    pub stx_nlink: u32,
+    pub stx_uid: u32,
+    pub stx_gid: u32,
+    pub stx_mode: u16,
+    pub __spare0: [u16; 1],
+    pub stx_ino: u64,
+    pub stx_size: u64,
+    pub stx_blocks: u64,
+    pub stx_attributes_mask: u64,
+    pub stx_atime: statx_timestamp,
+    pub stx_btime: statx_timestamp,
+    pub stx_ctime: statx_timestamp,
+    pub stx_mtime: statx_timestamp,
+    pub stx_rdev_major: u32,
+    pub stx_rdev_minor: u32,
+    pub stx_dev_major: u32,
+    pub stx_dev_minor: u32,
+    pub stx_mnt_id: u64,
+    pub stx_dio_mem_align: u32,
+    pub stx_dio_offset_align: u32,
+    pub stx_subvol: u64,
+    pub stx_atomic_write_unit_min: u32,
+    pub stx_atomic_write_unit_max: u32,
+    pub stx_atomic_write_segments_max: u32,
+    pub stx_dio_read_offset_align: u32,
+    pub stx_atomic_write_unit_max_opt: u32,
+    pub __spare2: [u32; 1],
+    pub __spare3: [u64; 8],
+}
+
+pub const STATX_TYPE: c_uint = 0x00000001;
+pub const STATX_MODE: c_uint = 0x00000002;
+pub const STATX_NLINK: c_uint = 0x00000004;
+pub const STATX_UID: c_uint = 0x00000008;
+pub const STATX_GID: c_uint = 0x00000010;
+pub const STATX_ATIME: c_uint = 0x00000020;
+pub const STATX_MTIME: c_uint = 0x00000040;
+pub const STATX_CTIME: c_uint = 0x00000080;
+pub const STATX_INO: c_uint = 0x00000100;
+pub const STATX_SIZE: c_uint = 0x00000200;
+pub const STATX_BLOCKS: c_uint = 0x00000400;
+pub const STATX_BASIC_STATS: c_uint = 0x000007ff;
+pub const STATX_BTIME: c_uint = 0x00000800;
+pub const STATX_MNT_ID: c_uint = 0x00001000;
+pub const STATX_DIOALIGN: c_uint = 0x00002000;
+pub const STATX_MNT_ID_UNIQUE: c_uint = 0x00004000;
+pub const STATX_SUBVOL: c_uint = 0x00008000;
+pub const STATX_WRITE_ATOMIC: c_uint = 0x00010000;
+pub const STATX_DIO_READ_ALIGN: c_uint = 0x00020000;
+pub const STATX_ALL: c_uint = 0x00000fff;
+pub const STATX__RESERVED: c_uint = 0x80000000;
+
+pub const STATX_ATTR_COMPRESSED: u64 = 0x00000004;
+pub const STATX_ATTR_IMMUTABLE: u64 = 0x00000010;
+pub const STATX_ATTR_APPEND: u64 = 0x00000020;
+pub const STATX_ATTR_NODUMP: u64 = 0x00000040;
+pub const STATX_ATTR_ENCRYPTED: u64 = 0x00000800;
+pub const STATX_ATTR_AUTOMOUNT: u64 = 0x00001000;
+pub const STATX_ATTR_MOUNT_ROOT: u64 = 0x00002000;
+pub const STATX_ATTR_VERITY: u64 = 0x00100000;
+pub const STATX_ATTR_DAX: u64 = 0x00200000;
+pub const STATX_ATTR_WRITE_ATOMIC: u64 = 0x00400000;
+
+pub const AT_STATX_SYNC_TYPE: c_int = 0x6000;
+pub const AT_STATX_SYNC_AS_STAT: c_int = 0x0000;
+pub const AT_STATX_FORCE_SYNC: c_int = 0x2000;
+pub const AT_STATX_DONT_SYNC: c_int = 0x4000;
+
+pub unsafe fn statx(
+    dirfd: c_int,
+    pathname: *const c_char,
+    flags: c_int,
+    mask: c_uint,
+    statxbuf: *mut statx,
+) -> c_int {
+    syscall(
+        SYS_statx,
+        dirfd as usize,
+        pathname as usize,
+        flags as usize,
+        mask as usize,
+        statxbuf as usize,
+    ) as c_int
+}
+
+/// The bit fields of the kernel structure are represented by the first member of each
+/// storage unit
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct tcp_info {
+    pub tcpi_state: u8,
+    pub tcpi_ca_state: u8,
+    pub tcpi_retransmits: u8,
+    pub tcpi_probes: u8,
+    pub tcpi_backoff: u8,
+    pub tcpi_options: u8,
+    /// tcpi_snd_wscale : 4, tcpi_rcv_wscale : 4
+    pub tcpi_snd_wscale: u8,
+    /// tcpi_delivery_rate_app_limited : 1, tcpi_fastopen_client_fail : 2
+    pub tcpi_delivery_rate_app_limited: u8,
+    pub tcpi_rto: u32,
+    pub tcpi_ato: u32,
+    pub tcpi_snd_mss: u32,
+    pub tcpi_rcv_mss: u32,
+    pub tcpi_unacked: u32,
+    pub tcpi_sacked: u32,
+    pub tcpi_lost: u32,
+    pub tcpi_retrans: u32,
+    pub tcpi_fackets: u32,
+    pub tcpi_last_data_sent: u32,
+    pub tcpi_last_ack_sent: u32,
+    pub tcpi_last_data_recv: u32,
+    pub tcpi_last_ack_recv: u32,
+    pub tcpi_pmtu: u32,
+    pub tcpi_rcv_ssthresh: u32,
+    pub tcpi_rtt: u32,
+    pub tcpi_rttvar: u32,
+    pub tcpi_snd_ssthresh: u32,
+    pub tcpi_snd_cwnd: u32,
+    pub tcpi_advmss: u32,
+    pub tcpi_reordering: u32,
+    pub tcpi_rcv_rtt: u32,
+    pub tcpi_rcv_space: u32,
+    pub tcpi_total_retrans: u32,
+    pub tcpi_pacing_rate: u64,
+    pub tcpi_max_pacing_rate: u64,
+    pub tcpi_bytes_acked: u64,
+    pub tcpi_bytes_received: u64,
+    pub tcpi_segs_out: u32,
+    pub tcpi_segs_in: u32,
+    pub tcpi_notsent_bytes: u32,
+    pub tcpi_min_rtt: u32,
+    pub tcpi_data_segs_in: u32,
+    pub tcpi_data_segs_out: u32,
+    pub tcpi_delivery_rate: u64,
+    pub tcpi_busy_time: u64,
+    pub tcpi_rwnd_limited: u64,
+    pub tcpi_sndbuf_limited: u64,
+    pub tcpi_delivered: u32,
+    pub tcpi_delivered_ce: u32,
+    pub tcpi_bytes_sent: u64,
+    pub tcpi_bytes_retrans: u64,
+    pub tcpi_dsack_dups: u32,
+    pub tcpi_reord_seen: u32,
+    pub tcpi_rcv_ooopack: u32,
+    pub tcpi_snd_wnd: u32,
+    pub tcpi_rcv_wnd: u32,
+    pub tcpi_rehash: u32,
+    pub tcpi_total_rto: u16,
+    pub tcpi_total_rto_recoveries: u16,
+    pub tcpi_total_rto_time: u32,
+}
+
+pub const TCP_ESTABLISHED: u8 = 1;
+pub const TCP_SYN_SENT: u8 = 2;
+pub const TCP_SYN_RECV: u8 = 3;
+pub const TCP_FIN_WAIT1: u8 = 4;
+pub const TCP_FIN_WAIT2: u8 = 5;
+pub const TCP_TIME_WAIT: u8 = 6;
+pub const TCP_CLOSE: u8 = 7;
+pub const TCP_CLOSE_WAIT: u8 = 8;
+pub const TCP_LAST_ACK: u8 = 9;
+pub const TCP_LISTEN: u8 = 10;
+pub const TCP_CLOSING: u8 = 11;
+
+cfg_if! {
+    if #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))] {
+        pub const SO_PASSPIDFD: c_int = 0x55;
+        pub const SO_PEERPIDFD: c_int = 0x56;
+    } else {
+        pub const SO_PASSPIDFD: c_int = 76;
+        pub const SO_PEERPIDFD: c_int = 77;
+    }
+}
+
+pub const SCM_PIDFD: c_int = 0x04;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct rtattr {
+    pub rta_len: c_ushort,
+    pub rta_type: c_ushort,
+}
+
+pub const NLM_F_CAPPED: c_int = 0x100;
+pub const NLM_F_ACK_TLVS: c_int = 0x200;
+
+pub const NLMSGERR_ATTR_UNUSED: c_int = 0;
+pub const NLMSGERR_ATTR_MSG: c_int = 1;
+pub const NLMSGERR_ATTR_OFFS: c_int = 2;
+pub const NLMSGERR_ATTR_COOKIE: c_int = 3;
+pub const NLMSGERR_ATTR_POLICY: c_int = 4;
+pub const NLMSGERR_ATTR_MISS_TYPE: c_int = 5;
+pub const NLMSGERR_ATTR_MISS_NEST: c_int = 6;
+
+pub const CTRL_CMD_GETPOLICY: c_int = 10;
+
+pub const CTRL_ATTR_POLICY: c_int = 8;
+pub const CTRL_ATTR_OP_POLICY: c_int = 9;
+pub const CTRL_ATTR_OP: c_int = 10;
+
+pub const CTRL_ATTR_POLICY_UNSPEC: c_int = 0;
+pub const CTRL_ATTR_POLICY_DO: c_int = 1;
+pub const CTRL_ATTR_POLICY_DUMP: c_int = 2;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct ifaddrmsg {
+    pub ifa_family: u8,
+    pub ifa_prefixlen: u8,
+    pub ifa_flags: u8,
+    pub ifa_scope: u8,
+    pub ifa_index: u32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct rtmsg {
+    pub rtm_family: c_uchar,
+    pub rtm_dst_len: c_uchar,
+    pub rtm_src_len: c_uchar,
+    pub rtm_tos: c_uchar,
+    pub rtm_table: c_uchar,
+    pub rtm_protocol: c_uchar,
+    pub rtm_scope: c_uchar,
+    pub rtm_type: c_uchar,
+    pub rtm_flags: c_uint,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct ndmsg {
+    pub ndm_family: u8,
+    pub ndm_pad1: u8,
+    pub ndm_pad2: u16,
+    pub ndm_ifindex: i32,
+    pub ndm_state: u16,
+    pub ndm_flags: u8,
+    pub ndm_type: u8,
+}
+
+pub const IFLA_DEVLINK_PORT: c_ushort = 62;
+pub const IFLA_GSO_IPV4_MAX_SIZE: c_ushort = 63;
+pub const IFLA_GRO_IPV4_MAX_SIZE: c_ushort = 64;
+pub const IFLA_DPLL_PIN: c_ushort = 65;
+pub const IFLA_MAX_PACING_OFFLOAD_HORIZON: c_ushort = 66;
+pub const IFLA_NETNS_IMMUTABLE: c_ushort = 67;
+
+pub const VETH_INFO_UNSPEC: c_ushort = 0;
+pub const VETH_INFO_PEER: c_ushort = 1;
+
+pub const IFA_FLAGS: c_ushort = 8;
+pub const IFA_RT_PRIORITY: c_ushort = 9;
+pub const IFA_TARGET_NETNSID: c_ushort = 10;
+pub const IFA_PROTO: c_ushort = 11;
+
+pub const IFA_F_MANAGETEMPADDR: u32 = 0x100;
+pub const IFA_F_NOPREFIXROUTE: u32 = 0x200;
+pub const IFA_F_MCAUTOJOIN: u32 = 0x400;
+pub const IFA_F_STABLE_PRIVACY: u32 = 0x800;
+
+pub const RTA_VIA: c_ushort = 18;
+pub const RTA_NEWDST: c_ushort = 19;
+pub const RTA_PREF: c_ushort = 20;
+pub const RTA_ENCAP_TYPE: c_ushort = 21;
+pub const RTA_ENCAP: c_ushort = 22;
+pub const RTA_EXPIRES: c_ushort = 23;
+pub const RTA_PAD: c_ushort = 24;
+pub const RTA_UID: c_ushort = 25;
+pub const RTA_TTL_PROPAGATE: c_ushort = 26;
+pub const RTA_IP_PROTO: c_ushort = 27;
+pub const RTA_SPORT: c_ushort = 28;
+pub const RTA_DPORT: c_ushort = 29;
+pub const RTA_NH_ID: c_ushort = 30;
+pub const RTA_FLOWLABEL: c_ushort = 31;
+
+pub const NDA_MASTER: c_ushort = 9;
+pub const NDA_LINK_NETNSID: c_ushort = 10;
+pub const NDA_SRC_VNI: c_ushort = 11;
+pub const NDA_PROTOCOL: c_ushort = 12;
+pub const NDA_NH_ID: c_ushort = 13;
+pub const NDA_FDB_EXT_ATTRS: c_ushort = 14;
+pub const NDA_FLAGS_EXT: c_ushort = 15;
+pub const NDA_NDM_STATE_MASK: c_ushort = 16;
+pub const NDA_NDM_FLAGS_MASK: c_ushort = 17;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct __user_cap_header_struct {
+    pub version: u32,
+    pub pid: c_int,
+}
+
+pub type cap_user_header_t = *mut __user_cap_header_struct;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct __user_cap_data_struct {
+    pub effective: u32,
+    pub permitted: u32,
+    pub inheritable: u32,
+}
+
+pub type cap_user_data_t = *mut __user_cap_data_struct;
+
+pub const _LINUX_CAPABILITY_VERSION_1: u32 = 0x19980330;
+pub const _LINUX_CAPABILITY_U32S_1: usize = 1;
+pub const _LINUX_CAPABILITY_VERSION_2: u32 = 0x20071026;
+pub const _LINUX_CAPABILITY_U32S_2: usize = 2;
+pub const _LINUX_CAPABILITY_VERSION_3: u32 = 0x20080522;
+pub const _LINUX_CAPABILITY_U32S_3: usize = 2;
+
+pub unsafe fn capget(hdrp: cap_user_header_t, datap: cap_user_data_t) -> c_int {
+    syscall(SYS_capget, hdrp as usize, datap as usize) as c_int
+}
+
+pub unsafe fn capset(
+    hdrp: cap_user_header_t,
+    datap: *const __user_cap_data_struct,
+) -> c_int {
+    syscall(SYS_capset, hdrp as usize, datap as usize) as c_int
+}
+
+pub const CAP_CHOWN: c_int = 0;
+pub const CAP_DAC_OVERRIDE: c_int = 1;
+pub const CAP_DAC_READ_SEARCH: c_int = 2;
+pub const CAP_FOWNER: c_int = 3;
+pub const CAP_FSETID: c_int = 4;
+pub const CAP_KILL: c_int = 5;
+pub const CAP_SETGID: c_int = 6;
+pub const CAP_SETUID: c_int = 7;
+pub const CAP_SETPCAP: c_int = 8;
+pub const CAP_LINUX_IMMUTABLE: c_int = 9;
+pub const CAP_NET_BIND_SERVICE: c_int = 10;
+pub const CAP_NET_BROADCAST: c_int = 11;
+pub const CAP_NET_ADMIN: c_int = 12;
+pub const CAP_NET_RAW: c_int = 13;
+pub const CAP_IPC_LOCK: c_int = 14;
+pub const CAP_IPC_OWNER: c_int = 15;
+pub const CAP_SYS_MODULE: c_int = 16;
+pub const CAP_SYS_RAWIO: c_int = 17;
+pub const CAP_SYS_CHROOT: c_int = 18;
+pub const CAP_SYS_PTRACE: c_int = 19;
+pub const CAP_SYS_PACCT: c_int = 20;
+pub const CAP_SYS_ADMIN: c_int = 21;
+pub const CAP_SYS_BOOT: c_int = 22;
+pub const CAP_SYS_NICE: c_int = 23;
+pub const CAP_SYS_RESOURCE: c_int = 24;
+pub const CAP_SYS_TIME: c_int = 25;
+pub const CAP_SYS_TTY_CONFIG: c_int = 26;
+pub const CAP_MKNOD: c_int = 27;
+pub const CAP_LEASE: c_int = 28;
+pub const CAP_AUDIT_WRITE: c_int = 29;
+pub const CAP_AUDIT_CONTROL: c_int = 30;
+pub const CAP_SETFCAP: c_int = 31;
+pub const CAP_MAC_OVERRIDE: c_int = 32;
+pub const CAP_MAC_ADMIN: c_int = 33;
+pub const CAP_SYSLOG: c_int = 34;
+pub const CAP_WAKE_ALARM: c_int = 35;
+pub const CAP_BLOCK_SUSPEND: c_int = 36;
+pub const CAP_AUDIT_READ: c_int = 37;
+pub const CAP_PERFMON: c_int = 38;
+pub const CAP_BPF: c_int = 39;
+pub const CAP_CHECKPOINT_RESTORE: c_int = 40;
+
+pub const CAP_LAST_CAP: c_int = CAP_CHECKPOINT_RESTORE;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct prctl_mm_map {
+    pub start_code: u64,
+    pub end_code: u64,
+    pub start_data: u64,
+    pub end_data: u64,
+    pub start_brk: u64,
+    pub brk: u64,
+    pub start_stack: u64,
+    pub arg_start: u64,
+    pub arg_end: u64,
+    pub env_start: u64,
+    pub env_end: u64,
+    pub auxv: *mut u64,
+    pub auxv_size: u32,
+    pub exe_fd: u32,
+}
+
+pub const SECCOMP_RET_USER_NOTIF: c_uint = 0x7fc00000;
+
+pub const SECCOMP_IOCTL_NOTIF_RECV: u64 = crate::_IOWR::<seccomp_notif>(b'!' as u64, 0);
+pub const SECCOMP_IOCTL_NOTIF_SEND: u64 =
+    crate::_IOWR::<seccomp_notif_resp>(b'!' as u64, 1);
+pub const SECCOMP_IOCTL_NOTIF_ID_VALID: u64 = crate::_IOW::<u64>(b'!' as u64, 2);
+pub const SECCOMP_IOCTL_NOTIF_ADDFD: u64 =
+    crate::_IOW::<seccomp_notif_addfd>(b'!' as u64, 3);
+pub const SECCOMP_IOCTL_NOTIF_SET_FLAGS: u64 = crate::_IOW::<u64>(b'!' as u64, 4);
+
+pub const SECCOMP_USER_NOTIF_FD_SYNC_WAKE_UP: u64 = 1;
+
+pub const BPF_A: u32 = 0x10;
+pub const BPF_TAX: u32 = 0x00;
+pub const BPF_TXA: u32 = 0x80;
+
+pub const SOCK_DIAG_BY_FAMILY: u16 = 20;
+pub const SOCK_DESTROY: u16 = 21;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct sock_diag_req {
+    pub sdiag_family: u8,
+    pub sdiag_protocol: u8,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct inet_diag_sockid {
+    pub idiag_sport: u16,
+    pub idiag_dport: u16,
+    pub idiag_src: [u32; 4],
+    pub idiag_dst: [u32; 4],
+    pub idiag_if: u32,
+    pub idiag_cookie: [u32; 2],
+}
+
+pub const INET_DIAG_NOCOOKIE: u32 = !0;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct inet_diag_req_v2 {
+    pub sdiag_family: u8,
+    pub sdiag_protocol: u8,
+    pub idiag_ext: u8,
+    pub pad: u8,
+    pub idiag_states: u32,
+    pub id: inet_diag_sockid,
+}
+
+pub const INET_DIAG_REQ_NONE: c_ushort = 0;
+pub const INET_DIAG_REQ_BYTECODE: c_ushort = 1;
+pub const INET_DIAG_REQ_SK_BPF_STORAGES: c_ushort = 2;
+pub const INET_DIAG_REQ_PROTOCOL: c_ushort = 3;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct inet_diag_msg {
+    pub idiag_family: u8,
+    pub idiag_state: u8,
+    pub idiag_timer: u8,
+    pub idiag_retrans: u8,
+    pub id: inet_diag_sockid,
+    pub idiag_expires: u32,
+    pub idiag_rqueue: u32,
+    pub idiag_wqueue: u32,
+    pub idiag_uid: u32,
+    pub idiag_inode: u32,
+}
+
+pub const INET_DIAG_NONE: c_ushort = 0;
+pub const INET_DIAG_MEMINFO: c_ushort = 1;
+pub const INET_DIAG_INFO: c_ushort = 2;
+pub const INET_DIAG_VEGASINFO: c_ushort = 3;
+pub const INET_DIAG_CONG: c_ushort = 4;
+pub const INET_DIAG_TOS: c_ushort = 5;
+pub const INET_DIAG_TCLASS: c_ushort = 6;
+pub const INET_DIAG_SKMEMINFO: c_ushort = 7;
+pub const INET_DIAG_SHUTDOWN: c_ushort = 8;
+pub const INET_DIAG_DCTCPINFO: c_ushort = 9;
+pub const INET_DIAG_PROTOCOL: c_ushort = 10;
+pub const INET_DIAG_SKV6ONLY: c_ushort = 11;
+pub const INET_DIAG_LOCALS: c_ushort = 12;
+pub const INET_DIAG_PEERS: c_ushort = 13;
+pub const INET_DIAG_PAD: c_ushort = 14;
+pub const INET_DIAG_MARK: c_ushort = 15;
+pub const INET_DIAG_BBRINFO: c_ushort = 16;
+pub const INET_DIAG_CLASS_ID: c_ushort = 17;
+pub const INET_DIAG_MD5SIG: c_ushort = 18;
+pub const INET_DIAG_ULP_INFO: c_ushort = 19;
+pub const INET_DIAG_SK_BPF_STORAGES: c_ushort = 20;
+pub const INET_DIAG_CGROUP_ID: c_ushort = 21;
+pub const INET_DIAG_SOCKOPT: c_ushort = 22;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct inet_diag_meminfo {
+    pub idiag_rmem: u32,
+    pub idiag_wmem: u32,
+    pub idiag_fmem: u32,
+    pub idiag_tmem: u32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct unix_diag_req {
+    pub sdiag_family: u8,
+    pub sdiag_protocol: u8,
+    pub pad: u16,
+    pub udiag_states: u32,
+    pub udiag_ino: u32,
+    pub udiag_show: u32,
+    pub udiag_cookie: [u32; 2],
+}
+
+pub const UDIAG_SHOW_NAME: u32 = 0x01;
+pub const UDIAG_SHOW_VFS: u32 = 0x02;
+pub const UDIAG_SHOW_PEER: u32 = 0x04;
+pub const UDIAG_SHOW_ICONS: u32 = 0x08;
+pub const UDIAG_SHOW_RQLEN: u32 = 0x10;
+pub const UDIAG_SHOW_MEMINFO: u32 = 0x20;
+pub const UDIAG_SHOW_UID: u32 = 0x40;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct unix_diag_msg {
+    pub udiag_family: u8,
+    pub udiag_type: u8,
+    pub udiag_state: u8,
+    pub pad: u8,
+    pub udiag_ino: u32,
+    pub udiag_cookie: [u32; 2],
+}
+
+pub const UNIX_DIAG_NAME: c_ushort = 0;
+pub const UNIX_DIAG_VFS: c_ushort = 1;
+pub const UNIX_DIAG_PEER: c_ushort = 2;
+pub const UNIX_DIAG_ICONS: c_ushort = 3;
+pub const UNIX_DIAG_RQLEN: c_ushort = 4;
+pub const UNIX_DIAG_MEMINFO: c_ushort = 5;
+pub const UNIX_DIAG_SHUTDOWN: c_ushort = 6;
+pub const UNIX_DIAG_UID: c_ushort = 7;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct unix_diag_vfs {
+    pub udiag_vfs_ino: u32,
+    pub udiag_vfs_dev: u32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct unix_diag_rqlen {
+    pub udiag_rqueue: u32,
+    pub udiag_wqueue: u32,
+}
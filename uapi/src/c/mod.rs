@@ -15,31 +15,147 @@ cfg_if! {
     if #[cfg(target_os = "linux")] {
         mod linux;
         pub use linux::{
-            fsconfig, fsmount, fsopen, fspick, ifinfomsg, move_mount, open_how, open_tree,
-            openat2, renameat2, sched_attr, sched_getattr, sched_getparam, sched_getscheduler,
-            sched_setattr, sched_setparam, sched_setscheduler, sockaddr_nl, uinput_setup,
-            SYS_clone3, SYS_close_range, SYS_epoll_pwait2, SYS_faccessat2, SYS_fsconfig,
-            SYS_fsmount, SYS_fsopen, SYS_fspick, SYS_futex_waitv, SYS_io_uring_enter,
-            SYS_io_uring_register, SYS_io_uring_setup, SYS_landlock_add_rule,
-            SYS_landlock_create_ruleset, SYS_landlock_restrict_self, SYS_memfd_secret,
-            SYS_mount_setattr, SYS_move_mount, SYS_open_tree, SYS_openat2, SYS_pidfd_getfd,
-            SYS_pidfd_open, SYS_pidfd_send_signal, SYS_process_madvise, SYS_process_mrelease,
-            SYS_quotactl_fd, SYS_set_mempolicy_home_node, AT_RECURSIVE, CLOSE_RANGE_CLOEXEC,
-            CLOSE_RANGE_UNSHARE, FSCONFIG_CMD_CREATE, FSCONFIG_CMD_RECONFIGURE,
-            FSCONFIG_SET_BINARY, FSCONFIG_SET_FD, FSCONFIG_SET_FLAG, FSCONFIG_SET_PATH,
-            FSCONFIG_SET_PATH_EMPTY, FSCONFIG_SET_STRING, FSMOUNT_CLOEXEC, FSOPEN_CLOEXEC,
-            FSPICK_CLOEXEC, FSPICK_EMPTY_PATH, FSPICK_NO_AUTOMOUNT, FSPICK_SYMLINK_NOFOLLOW,
+            cap_user_data_t, cap_user_header_t, capget, capset, clone3, clone_args, fsconfig,
+            fsmount, fsopen, fspick, futex, futex_waitv, ifaddrmsg, ifinfomsg,
+            inet_diag_meminfo, inet_diag_msg, inet_diag_req_v2, inet_diag_sockid,
+            io_cqring_offsets, io_sqring_offsets, io_uring_cqe, io_uring_enter,
+            io_uring_params, io_uring_register, io_uring_setup, io_uring_sqe,
+            landlock_add_rule, landlock_create_ruleset, landlock_net_port_attr,
+            landlock_path_beneath_attr, landlock_restrict_self, landlock_ruleset_attr,
+            listmount, mnt_id_req, mount_attr, mount_setattr, move_mount, ndmsg, open_how,
+            open_tree, openat2, prctl_mm_map, renameat2, rtattr, rtmsg, sched_attr,
+            sched_getattr, sched_getparam, sched_getscheduler, sched_setattr, sched_setparam,
+            sched_setscheduler, sock_diag_req, sockaddr_nl, statmount, statx, statx_timestamp,
+            tcp_info, uinput_setup, unix_diag_msg, unix_diag_req, unix_diag_rqlen,
+            unix_diag_vfs, SYS_clone3, SYS_close_range, SYS_epoll_pwait2, SYS_faccessat2,
+            SYS_fsconfig, SYS_fsmount, SYS_fsopen, SYS_fspick, SYS_futex_waitv,
+            SYS_io_uring_enter, SYS_io_uring_register, SYS_io_uring_setup,
+            SYS_landlock_add_rule, SYS_landlock_create_ruleset, SYS_landlock_restrict_self,
+            SYS_listmount, SYS_memfd_secret, SYS_mount_setattr, SYS_move_mount, SYS_open_tree,
+            SYS_openat2, SYS_pidfd_getfd, SYS_pidfd_open, SYS_pidfd_send_signal,
+            SYS_process_madvise, SYS_process_mrelease, SYS_quotactl_fd,
+            SYS_set_mempolicy_home_node, SYS_statmount, __kernel_timespec,
+            __user_cap_data_struct, __user_cap_header_struct, AT_RECURSIVE,
+            AT_STATX_DONT_SYNC, AT_STATX_FORCE_SYNC, AT_STATX_SYNC_AS_STAT,
+            AT_STATX_SYNC_TYPE, BPF_A, BPF_TAX, BPF_TXA, CAP_AUDIT_CONTROL, CAP_AUDIT_READ,
+            CAP_AUDIT_WRITE, CAP_BLOCK_SUSPEND, CAP_BPF, CAP_CHECKPOINT_RESTORE, CAP_CHOWN,
+            CAP_DAC_OVERRIDE, CAP_DAC_READ_SEARCH, CAP_FOWNER, CAP_FSETID, CAP_IPC_LOCK,
+            CAP_IPC_OWNER, CAP_KILL, CAP_LAST_CAP, CAP_LEASE, CAP_LINUX_IMMUTABLE,
+            CAP_MAC_ADMIN, CAP_MAC_OVERRIDE, CAP_MKNOD, CAP_NET_ADMIN, CAP_NET_BIND_SERVICE,
+            CAP_NET_BROADCAST, CAP_NET_RAW, CAP_PERFMON, CAP_SETFCAP, CAP_SETGID, CAP_SETPCAP,
+            CAP_SETUID, CAP_SYSLOG, CAP_SYS_ADMIN, CAP_SYS_BOOT, CAP_SYS_CHROOT,
+            CAP_SYS_MODULE, CAP_SYS_NICE, CAP_SYS_PACCT, CAP_SYS_PTRACE, CAP_SYS_RAWIO,
+            CAP_SYS_RESOURCE, CAP_SYS_TIME, CAP_SYS_TTY_CONFIG, CAP_WAKE_ALARM,
+            CLONE_CLEAR_SIGHAND, CLONE_INTO_CGROUP, CLOSE_RANGE_CLOEXEC, CLOSE_RANGE_UNSHARE,
+            CTRL_ATTR_OP, CTRL_ATTR_OP_POLICY, CTRL_ATTR_POLICY, CTRL_ATTR_POLICY_DO,
+            CTRL_ATTR_POLICY_DUMP, CTRL_ATTR_POLICY_UNSPEC, CTRL_CMD_GETPOLICY,
+            FSCONFIG_CMD_CREATE, FSCONFIG_CMD_RECONFIGURE, FSCONFIG_SET_BINARY,
+            FSCONFIG_SET_FD, FSCONFIG_SET_FLAG, FSCONFIG_SET_PATH, FSCONFIG_SET_PATH_EMPTY,
+            FSCONFIG_SET_STRING, FSMOUNT_CLOEXEC, FSOPEN_CLOEXEC, FSPICK_CLOEXEC,
+            FSPICK_EMPTY_PATH, FSPICK_NO_AUTOMOUNT, FSPICK_SYMLINK_NOFOLLOW, FUTEX2_NUMA,
+            FUTEX2_PRIVATE, FUTEX2_SIZE_U16, FUTEX2_SIZE_U32, FUTEX2_SIZE_U64, FUTEX2_SIZE_U8,
+            FUTEX_32, FUTEX_OWNER_DIED, FUTEX_TID_MASK, FUTEX_WAITERS, FUTEX_WAITV_MAX,
+            IFA_FLAGS, IFA_F_MANAGETEMPADDR, IFA_F_MCAUTOJOIN, IFA_F_NOPREFIXROUTE,
+            IFA_F_STABLE_PRIVACY, IFA_PROTO, IFA_RT_PRIORITY, IFA_TARGET_NETNSID,
+            IFLA_DEVLINK_PORT, IFLA_DPLL_PIN, IFLA_GRO_IPV4_MAX_SIZE, IFLA_GSO_IPV4_MAX_SIZE,
+            IFLA_MAX_PACING_OFFLOAD_HORIZON, IFLA_NETNS_IMMUTABLE, INET_DIAG_BBRINFO,
+            INET_DIAG_CGROUP_ID, INET_DIAG_CLASS_ID, INET_DIAG_CONG, INET_DIAG_DCTCPINFO,
+            INET_DIAG_INFO, INET_DIAG_LOCALS, INET_DIAG_MARK, INET_DIAG_MD5SIG,
+            INET_DIAG_MEMINFO, INET_DIAG_NOCOOKIE, INET_DIAG_NONE, INET_DIAG_PAD,
+            INET_DIAG_PEERS, INET_DIAG_PROTOCOL, INET_DIAG_REQ_BYTECODE, INET_DIAG_REQ_NONE,
+            INET_DIAG_REQ_PROTOCOL, INET_DIAG_REQ_SK_BPF_STORAGES, INET_DIAG_SHUTDOWN,
+            INET_DIAG_SKMEMINFO, INET_DIAG_SKV6ONLY, INET_DIAG_SK_BPF_STORAGES,
+            INET_DIAG_SOCKOPT, INET_DIAG_TCLASS, INET_DIAG_TOS, INET_DIAG_ULP_INFO,
+            INET_DIAG_VEGASINFO, IORING_CQE_BUFFER_SHIFT, IORING_CQE_F_BUFFER,
+            IORING_CQE_F_MORE, IORING_CQE_F_NOTIF, IORING_CQE_F_SOCK_NONEMPTY,
+            IORING_CQ_EVENTFD_DISABLED, IORING_ENTER_EXT_ARG, IORING_ENTER_GETEVENTS,
+            IORING_ENTER_REGISTERED_RING, IORING_ENTER_SQ_WAIT, IORING_ENTER_SQ_WAKEUP,
+            IORING_FEAT_CQE_SKIP, IORING_FEAT_CUR_PERSONALITY, IORING_FEAT_EXT_ARG,
+            IORING_FEAT_FAST_POLL, IORING_FEAT_LINKED_FILE, IORING_FEAT_NATIVE_WORKERS,
+            IORING_FEAT_NODROP, IORING_FEAT_POLL_32BITS, IORING_FEAT_RSRC_TAGS,
+            IORING_FEAT_RW_CUR_POS, IORING_FEAT_SINGLE_MMAP, IORING_FEAT_SQPOLL_NONFIXED,
+            IORING_FEAT_SUBMIT_STABLE, IORING_FSYNC_DATASYNC, IORING_OFF_CQ_RING,
+            IORING_OFF_SQES, IORING_OFF_SQ_RING, IORING_OP_ACCEPT, IORING_OP_ASYNC_CANCEL,
+            IORING_OP_CLOSE, IORING_OP_CONNECT, IORING_OP_EPOLL_CTL, IORING_OP_FADVISE,
+            IORING_OP_FALLOCATE, IORING_OP_FGETXATTR, IORING_OP_FILES_UPDATE,
+            IORING_OP_FSETXATTR, IORING_OP_FSYNC, IORING_OP_GETXATTR, IORING_OP_LINKAT,
+            IORING_OP_LINK_TIMEOUT, IORING_OP_MADVISE, IORING_OP_MKDIRAT, IORING_OP_MSG_RING,
+            IORING_OP_NOP, IORING_OP_OPENAT, IORING_OP_OPENAT2, IORING_OP_POLL_ADD,
+            IORING_OP_POLL_REMOVE, IORING_OP_PROVIDE_BUFFERS, IORING_OP_READ, IORING_OP_READV,
+            IORING_OP_READ_FIXED, IORING_OP_RECV, IORING_OP_RECVMSG, IORING_OP_REMOVE_BUFFERS,
+            IORING_OP_RENAMEAT, IORING_OP_SEND, IORING_OP_SENDMSG, IORING_OP_SENDMSG_ZC,
+            IORING_OP_SEND_ZC, IORING_OP_SETXATTR, IORING_OP_SHUTDOWN, IORING_OP_SOCKET,
+            IORING_OP_SPLICE, IORING_OP_STATX, IORING_OP_SYMLINKAT, IORING_OP_SYNC_FILE_RANGE,
+            IORING_OP_TEE, IORING_OP_TIMEOUT, IORING_OP_TIMEOUT_REMOVE, IORING_OP_UNLINKAT,
+            IORING_OP_URING_CMD, IORING_OP_WRITE, IORING_OP_WRITEV, IORING_OP_WRITE_FIXED,
+            IORING_REGISTER_BUFFERS, IORING_REGISTER_ENABLE_RINGS, IORING_REGISTER_EVENTFD,
+            IORING_REGISTER_EVENTFD_ASYNC, IORING_REGISTER_FILES,
+            IORING_REGISTER_FILES_UPDATE, IORING_REGISTER_PERSONALITY, IORING_REGISTER_PROBE,
+            IORING_REGISTER_RESTRICTIONS, IORING_SETUP_ATTACH_WQ, IORING_SETUP_CLAMP,
+            IORING_SETUP_COOP_TASKRUN, IORING_SETUP_CQE32, IORING_SETUP_CQSIZE,
+            IORING_SETUP_DEFER_TASKRUN, IORING_SETUP_IOPOLL, IORING_SETUP_NO_MMAP,
+            IORING_SETUP_NO_SQARRAY, IORING_SETUP_REGISTERED_FD_ONLY, IORING_SETUP_R_DISABLED,
+            IORING_SETUP_SINGLE_ISSUER, IORING_SETUP_SQE128, IORING_SETUP_SQPOLL,
+            IORING_SETUP_SQ_AFF, IORING_SETUP_SUBMIT_ALL, IORING_SETUP_TASKRUN_FLAG,
+            IORING_SQ_CQ_OVERFLOW, IORING_SQ_NEED_WAKEUP, IORING_SQ_TASKRUN,
+            IORING_TIMEOUT_ABS, IORING_UNREGISTER_BUFFERS, IORING_UNREGISTER_EVENTFD,
+            IORING_UNREGISTER_FILES, IORING_UNREGISTER_PERSONALITY, IOSQE_ASYNC,
+            IOSQE_BUFFER_SELECT, IOSQE_CQE_SKIP_SUCCESS, IOSQE_FIXED_FILE, IOSQE_IO_DRAIN,
+            IOSQE_IO_HARDLINK, IOSQE_IO_LINK, LANDLOCK_ACCESS_FS_EXECUTE,
+            LANDLOCK_ACCESS_FS_IOCTL_DEV, LANDLOCK_ACCESS_FS_MAKE_BLOCK,
+            LANDLOCK_ACCESS_FS_MAKE_CHAR, LANDLOCK_ACCESS_FS_MAKE_DIR,
+            LANDLOCK_ACCESS_FS_MAKE_FIFO, LANDLOCK_ACCESS_FS_MAKE_REG,
+            LANDLOCK_ACCESS_FS_MAKE_SOCK, LANDLOCK_ACCESS_FS_MAKE_SYM,
+            LANDLOCK_ACCESS_FS_READ_DIR, LANDLOCK_ACCESS_FS_READ_FILE,
+            LANDLOCK_ACCESS_FS_REFER, LANDLOCK_ACCESS_FS_REMOVE_DIR,
+            LANDLOCK_ACCESS_FS_REMOVE_FILE, LANDLOCK_ACCESS_FS_TRUNCATE,
+            LANDLOCK_ACCESS_FS_WRITE_FILE, LANDLOCK_ACCESS_NET_BIND_TCP,
+            LANDLOCK_ACCESS_NET_CONNECT_TCP, LANDLOCK_CREATE_RULESET_VERSION,
+            LANDLOCK_RULE_NET_PORT, LANDLOCK_RULE_PATH_BENEATH,
+            LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET, LANDLOCK_SCOPE_SIGNAL, LISTMOUNT_REVERSE,
+            LSMT_ROOT, MNT_ID_REQ_SIZE_VER0, MNT_ID_REQ_SIZE_VER1, MOUNT_ATTR_IDMAP,
             MOUNT_ATTR_NOATIME, MOUNT_ATTR_NODEV, MOUNT_ATTR_NODIRATIME, MOUNT_ATTR_NOEXEC,
-            MOUNT_ATTR_NOSUID, MOUNT_ATTR_RDONLY, MOUNT_ATTR_RELATIME, MOUNT_ATTR_STRICTATIME,
-            MOUNT_ATTR__ATIME, MOVE_MOUNT_F_AUTOMOUNTS, MOVE_MOUNT_F_EMPTY_PATH,
-            MOVE_MOUNT_F_SYMLINKS, MOVE_MOUNT_T_AUTOMOUNTS, MOVE_MOUNT_T_EMPTY_PATH,
-            MOVE_MOUNT_T_SYMLINKS, MOVE_MOUNT__MASK, OPEN_TREE_CLOEXEC, OPEN_TREE_CLONE,
+            MOUNT_ATTR_NOSUID, MOUNT_ATTR_NOSYMFOLLOW, MOUNT_ATTR_RDONLY, MOUNT_ATTR_RELATIME,
+            MOUNT_ATTR_SIZE_VER0, MOUNT_ATTR_STRICTATIME, MOUNT_ATTR__ATIME,
+            MOVE_MOUNT_F_AUTOMOUNTS, MOVE_MOUNT_F_EMPTY_PATH, MOVE_MOUNT_F_SYMLINKS,
+            MOVE_MOUNT_T_AUTOMOUNTS, MOVE_MOUNT_T_EMPTY_PATH, MOVE_MOUNT_T_SYMLINKS,
+            MOVE_MOUNT__MASK, NDA_FDB_EXT_ATTRS, NDA_FLAGS_EXT, NDA_LINK_NETNSID, NDA_MASTER,
+            NDA_NDM_FLAGS_MASK, NDA_NDM_STATE_MASK, NDA_NH_ID, NDA_PROTOCOL, NDA_SRC_VNI,
+            NLMSGERR_ATTR_COOKIE, NLMSGERR_ATTR_MISS_NEST, NLMSGERR_ATTR_MISS_TYPE,
+            NLMSGERR_ATTR_MSG, NLMSGERR_ATTR_OFFS, NLMSGERR_ATTR_POLICY, NLMSGERR_ATTR_UNUSED,
+            NLM_F_ACK_TLVS, NLM_F_CAPPED, OPEN_TREE_CLOEXEC, OPEN_TREE_CLONE, PIDFD_NONBLOCK,
             RESOLVE_BENEATH, RESOLVE_IN_ROOT, RESOLVE_NO_MAGICLINKS, RESOLVE_NO_SYMLINKS,
-            RESOLVE_NO_XDEV, RWF_APPEND, RWF_DSYNC, RWF_HIPRI, RWF_NOWAIT, RWF_SYNC,
-            SCHED_FLAG_ALL, SCHED_FLAG_DL_OVERRUN, SCHED_FLAG_KEEP_ALL, SCHED_FLAG_KEEP_PARAMS,
-            SCHED_FLAG_KEEP_POLICY, SCHED_FLAG_RECLAIM, SCHED_FLAG_RESET_ON_FORK,
-            SCHED_FLAG_UTIL_CLAMP, SCHED_FLAG_UTIL_CLAMP_MAX, SCHED_FLAG_UTIL_CLAMP_MIN,
-            UINPUT_IOCTL_BASE, UINPUT_MAX_NAME_SIZE, PIDFD_NONBLOCK,
+            RESOLVE_NO_XDEV, RTA_DPORT, RTA_ENCAP, RTA_ENCAP_TYPE, RTA_EXPIRES, RTA_FLOWLABEL,
+            RTA_IP_PROTO, RTA_NEWDST, RTA_NH_ID, RTA_PAD, RTA_PREF, RTA_SPORT,
+            RTA_TTL_PROPAGATE, RTA_UID, RTA_VIA, RWF_APPEND, RWF_DSYNC, RWF_HIPRI, RWF_NOWAIT,
+            RWF_SYNC, SCHED_FLAG_ALL, SCHED_FLAG_DL_OVERRUN, SCHED_FLAG_KEEP_ALL,
+            SCHED_FLAG_KEEP_PARAMS, SCHED_FLAG_KEEP_POLICY, SCHED_FLAG_RECLAIM,
+            SCHED_FLAG_RESET_ON_FORK, SCHED_FLAG_UTIL_CLAMP, SCHED_FLAG_UTIL_CLAMP_MAX,
+            SCHED_FLAG_UTIL_CLAMP_MIN, SCM_PIDFD, SECCOMP_IOCTL_NOTIF_ADDFD,
+            SECCOMP_IOCTL_NOTIF_ID_VALID, SECCOMP_IOCTL_NOTIF_RECV, SECCOMP_IOCTL_NOTIF_SEND,
+            SECCOMP_IOCTL_NOTIF_SET_FLAGS, SECCOMP_RET_USER_NOTIF,
+            SECCOMP_USER_NOTIF_FD_SYNC_WAKE_UP, SOCK_DESTROY, SOCK_DIAG_BY_FAMILY,
+            SO_PASSPIDFD, SO_PEERPIDFD, STATMOUNT_FS_SUBTYPE, STATMOUNT_FS_TYPE,
+            STATMOUNT_MNT_BASIC, STATMOUNT_MNT_GIDMAP, STATMOUNT_MNT_NS_ID,
+            STATMOUNT_MNT_OPTS, STATMOUNT_MNT_POINT, STATMOUNT_MNT_ROOT, STATMOUNT_MNT_UIDMAP,
+            STATMOUNT_OPT_ARRAY, STATMOUNT_OPT_SEC_ARRAY, STATMOUNT_PROPAGATE_FROM,
+            STATMOUNT_SB_BASIC, STATMOUNT_SB_SOURCE, STATMOUNT_SUPPORTED_MASK, STATX_ALL,
+            STATX_ATIME, STATX_ATTR_APPEND, STATX_ATTR_AUTOMOUNT, STATX_ATTR_COMPRESSED,
+            STATX_ATTR_DAX, STATX_ATTR_ENCRYPTED, STATX_ATTR_IMMUTABLE, STATX_ATTR_MOUNT_ROOT,
+            STATX_ATTR_NODUMP, STATX_ATTR_VERITY, STATX_ATTR_WRITE_ATOMIC, STATX_BASIC_STATS,
+            STATX_BLOCKS, STATX_BTIME, STATX_CTIME, STATX_DIOALIGN, STATX_DIO_READ_ALIGN,
+            STATX_GID, STATX_INO, STATX_MNT_ID, STATX_MNT_ID_UNIQUE, STATX_MODE, STATX_MTIME,
+            STATX_NLINK, STATX_SIZE, STATX_SUBVOL, STATX_TYPE, STATX_UID, STATX_WRITE_ATOMIC,
+            STATX__RESERVED, TCP_CLOSE, TCP_CLOSE_WAIT, TCP_CLOSING, TCP_ESTABLISHED,
+            TCP_FIN_WAIT1, TCP_FIN_WAIT2, TCP_LAST_ACK, TCP_LISTEN, TCP_SYN_RECV,
+            TCP_SYN_SENT, TCP_TIME_WAIT, UDIAG_SHOW_ICONS, UDIAG_SHOW_MEMINFO,
+            UDIAG_SHOW_NAME, UDIAG_SHOW_PEER, UDIAG_SHOW_RQLEN, UDIAG_SHOW_UID,
+            UDIAG_SHOW_VFS, UINPUT_IOCTL_BASE, UINPUT_MAX_NAME_SIZE, UNIX_DIAG_ICONS,
+            UNIX_DIAG_MEMINFO, UNIX_DIAG_NAME, UNIX_DIAG_PEER, UNIX_DIAG_RQLEN,
+            UNIX_DIAG_SHUTDOWN, UNIX_DIAG_UID, UNIX_DIAG_VFS, VETH_INFO_PEER,
+            VETH_INFO_UNSPEC, _LINUX_CAPABILITY_U32S_1, _LINUX_CAPABILITY_U32S_2,
+            _LINUX_CAPABILITY_U32S_3, _LINUX_CAPABILITY_VERSION_1,
+            _LINUX_CAPABILITY_VERSION_2, _LINUX_CAPABILITY_VERSION_3,
         };
     }
 }
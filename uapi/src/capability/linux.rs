@@ -0,0 +1,165 @@
+use crate::*;
+use std::ptr;
+
+fn cap_data_len(version: u32) -> Result<usize> {
+    match version {
+        c::_LINUX_CAPABILITY_VERSION_1 => Ok(c::_LINUX_CAPABILITY_U32S_1),
+        c::_LINUX_CAPABILITY_VERSION_2 => Ok(c::_LINUX_CAPABILITY_U32S_2),
+        c::_LINUX_CAPABILITY_VERSION_3 => Ok(c::_LINUX_CAPABILITY_U32S_3),
+        _ => einval(),
+    }
+}
+
+#[man(capget(2))]
+///
+/// If `data` is `None`, the kernel stores its preferred version in `hdr.version`.
+/// Otherwise `data` must contain at least as many elements as required by
+/// `hdr.version`.
+pub fn capget(
+    hdr: &mut c::__user_cap_header_struct,
+    data: Option<&mut [c::__user_cap_data_struct]>,
+) -> Result<()> {
+    let data = match data {
+        Some(data) => {
+            if data.len() < cap_data_len(hdr.version)? {
+                return einval();
+            }
+            data.as_mut_ptr()
+        }
+        _ => ptr::null_mut(),
+    };
+    let res = unsafe { c::capget(hdr, data) };
+    map_err!(res).map(drop)
+}
+
+#[man(capset(2))]
+///
+/// `data` must contain at least as many elements as required by `hdr.version`.
+pub fn capset(
+    hdr: &c::__user_cap_header_struct,
+    data: &[c::__user_cap_data_struct],
+) -> Result<()> {
+    if data.len() < cap_data_len(hdr.version)? {
+        return einval();
+    }
+    let mut hdr = *hdr;
+    let res = unsafe { c::capset(&mut hdr, data.as_ptr()) };
+    map_err!(res).map(drop)
+}
+
+/// The capability sets of a thread
+///
+/// Bit `n` of each set corresponds to the capability `n`, e.g., `1 << CAP_CHOWN`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CapSets {
+    /// The effective set
+    pub effective: u64,
+    /// The permitted set
+    pub permitted: u64,
+    /// The inheritable set
+    pub inheritable: u64,
+}
+
+/// Returns the capability sets of a thread
+///
+/// If `pid` is `0`, the sets of the calling thread are returned. This uses
+/// `capget` with `_LINUX_CAPABILITY_VERSION_3`.
+pub fn capget_sets(pid: c::pid_t) -> Result<CapSets> {
+    let mut hdr = c::__user_cap_header_struct {
+        version: c::_LINUX_CAPABILITY_VERSION_3,
+        pid,
+    };
+    let mut data = [pod_zeroed::<c::__user_cap_data_struct>(); 2];
+    capget(&mut hdr, Some(&mut data))?;
+    let join = |f: fn(&c::__user_cap_data_struct) -> u32| {
+        f(&data[0]) as u64 | (f(&data[1]) as u64) << 32
+    };
+    Ok(CapSets {
+        effective: join(|d| d.effective),
+        permitted: join(|d| d.permitted),
+        inheritable: join(|d| d.inheritable),
+    })
+}
+
+/// Sets the capability sets of the calling thread
+///
+/// This uses `capset` with `_LINUX_CAPABILITY_VERSION_3`.
+pub fn capset_sets(sets: &CapSets) -> Result<()> {
+    let hdr = c::__user_cap_header_struct {
+        version: c::_LINUX_CAPABILITY_VERSION_3,
+        pid: 0,
+    };
+    let split = |shift: u32| c::__user_cap_data_struct {
+        effective: (sets.effective >> shift) as u32,
+        permitted: (sets.permitted >> shift) as u32,
+        inheritable: (sets.inheritable >> shift) as u32,
+    };
+    capset(&hdr, &[split(0), split(32)])
+}
+
+/// Calls `f` for every capability supported by the kernel
+///
+/// Stops at the first capability for which `f` returns `EINVAL`.
+fn cap_each(mut f: impl FnMut(c::c_int) -> Result<()>) -> Result<()> {
+    for cap in 0..64 {
+        match f(cap) {
+            Err(Errno(c::EINVAL)) => break,
+            res => res?,
+        }
+    }
+    Ok(())
+}
+
+/// Returns the ambient capability set of the calling thread
+///
+/// This uses `prctl_cap_ambient_is_set` for every capability supported by the kernel.
+pub fn cap_ambient_get() -> Result<u64> {
+    let mut set = 0;
+    cap_each(|cap| {
+        if prctl_cap_ambient_is_set(cap)? != 0 {
+            set |= 1 << cap;
+        }
+        Ok(())
+    })?;
+    Ok(set)
+}
+
+/// Replaces the ambient capability set of the calling thread
+///
+/// This clears the ambient set and then raises every capability in `set`. Each
+/// capability must be in the permitted and inheritable sets.
+pub fn cap_ambient_set(set: u64) -> Result<()> {
+    prctl_cap_ambient_clear_all()?;
+    for cap in 0..64 {
+        if set & (1 << cap) != 0 {
+            prctl_cap_ambient_raise(cap)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the capability bounding set of the calling thread
+///
+/// This uses `prctl_capbset_read` for every capability supported by the kernel.
+pub fn cap_bounding_get() -> Result<u64> {
+    let mut set = 0;
+    cap_each(|cap| {
+        if prctl_capbset_read(cap)? != 0 {
+            set |= 1 << cap;
+        }
+        Ok(())
+    })?;
+    Ok(set)
+}
+
+/// Drops capabilities from the bounding set of the calling thread
+///
+/// This calls `prctl_capbset_drop` for every capability in `set`.
+pub fn cap_bounding_drop(set: u64) -> Result<()> {
+    for cap in 0..64 {
+        if set & (1 << cap) != 0 {
+            prctl_capbset_drop(cap)?;
+        }
+    }
+    Ok(())
+}
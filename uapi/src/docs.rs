@@ -24,6 +24,15 @@
 //! An `OwnedFd` can be created from any integer by calling `OwnedFd::new`. An `OwnedFd`
 //! can be unwrapped by calling `OwnedFd::unwrap`.
 //!
+//! # Memory Mappings
+//!
+//! `Mmap` is an owned memory mapping. It implements `Drop` and `Deref` to `[u8]`. Like
+//! `OwnedFd`, it can be created from and unwrapped into its raw parts with
+//! `Mmap::from_raw` and `Mmap::unwrap`.
+//!
+//! Creating a file-backed mapping is unsafe because the contents of the mapping can be
+//! changed or invalidated by other processes.
+//!
 //! # Strings
 //!
 //! This crate contains 3 string types:
@@ -114,3 +123,7 @@
 //!
 //! - `cmsg_write` - Writes a cmsg to a byte buffer
 //! - `cmsg_read` - Reads a cmsg from a byte buffer
+//! - `cmsg_iter` - Iterates over the cmsgs in a byte buffer
+//!
+//! Common cmsgs can be written and read with typed functions such as
+//! `cmsg_write_scm_rights` and `cmsg_read_scm_rights`.
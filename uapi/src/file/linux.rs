@@ -327,3 +327,16 @@ pub fn close_range(first: c::c_uint, last: c::c_uint, flags: c::c_uint) -> Resul
     };
     map_err!(val).map(drop)
 }
+
+#[man(statx(2))]
+pub fn statx<'a>(
+    dirfd: c::c_int,
+    pathname: impl IntoUstr<'a>,
+    flags: c::c_int,
+    mask: c::c_uint,
+) -> Result<c::statx> {
+    let pathname = pathname.into_ustr();
+    let mut statx = pod_zeroed();
+    let val = unsafe { c::statx(dirfd, pathname.as_ptr(), flags, mask, &mut statx) };
+    map_err!(val).map(|_| statx)
+}
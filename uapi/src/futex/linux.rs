@@ -0,0 +1,337 @@
+use crate::*;
+use std::{convert::TryFrom, ptr, sync::atomic::AtomicU32};
+
+fn uaddr(a: &AtomicU32) -> *mut u32 {
+    a as *const AtomicU32 as *mut u32
+}
+
+fn timeout_ptr(timeout: Option<&c::timespec>) -> *const c::timespec {
+    timeout.map(|t| t as *const _).unwrap_or(ptr::null())
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAIT`")]
+///
+/// `flags` is or'ed into `futex_op`, e.g. `FUTEX_PRIVATE_FLAG`. `timeout` is relative.
+pub fn futex_wait(
+    uaddr: &AtomicU32,
+    val: u32,
+    timeout: Option<&c::timespec>,
+    flags: c::c_int,
+) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAIT | flags,
+            val,
+            timeout_ptr(timeout),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAKE`")]
+///
+/// `flags` is or'ed into `futex_op`. Returns the number of woken waiters.
+pub fn futex_wake(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAKE | flags,
+            nr_wake as u32,
+            ptr::null(),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_REQUEUE`")]
+///
+/// `flags` is or'ed into `futex_op`. Returns the number of woken waiters.
+pub fn futex_requeue(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    uaddr2: &AtomicU32,
+    nr_requeue: c::c_int,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_REQUEUE | flags,
+            nr_wake as u32,
+            nr_requeue as usize as *const _,
+            self::uaddr(uaddr2),
+            0,
+        )
+    };
+    map_err!(res)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_CMP_REQUEUE`")]
+///
+/// `flags` is or'ed into `futex_op`. Returns the number of woken and requeued waiters.
+pub fn futex_cmp_requeue(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    uaddr2: &AtomicU32,
+    nr_requeue: c::c_int,
+    val3: u32,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_CMP_REQUEUE | flags,
+            nr_wake as u32,
+            nr_requeue as usize as *const _,
+            self::uaddr(uaddr2),
+            val3,
+        )
+    };
+    map_err!(res)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAKE_OP`")]
+///
+/// `flags` is or'ed into `futex_op`. `val3` is usually constructed with
+/// `libc::FUTEX_OP`. Returns the number of woken waiters.
+pub fn futex_wake_op(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    uaddr2: &AtomicU32,
+    nr_wake2: c::c_int,
+    val3: c::c_int,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAKE_OP | flags,
+            nr_wake as u32,
+            nr_wake2 as usize as *const _,
+            self::uaddr(uaddr2),
+            val3 as u32,
+        )
+    };
+    map_err!(res)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAIT_BITSET`")]
+///
+/// `flags` is or'ed into `futex_op`, e.g. `FUTEX_CLOCK_REALTIME`. `timeout` is absolute.
+pub fn futex_wait_bitset(
+    uaddr: &AtomicU32,
+    val: u32,
+    timeout: Option<&c::timespec>,
+    bitset: u32,
+    flags: c::c_int,
+) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAIT_BITSET | flags,
+            val,
+            timeout_ptr(timeout),
+            ptr::null_mut(),
+            bitset,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAKE_BITSET`")]
+///
+/// `flags` is or'ed into `futex_op`. Returns the number of woken waiters.
+pub fn futex_wake_bitset(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    bitset: u32,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAKE_BITSET | flags,
+            nr_wake as u32,
+            ptr::null(),
+            ptr::null_mut(),
+            bitset,
+        )
+    };
+    map_err!(res)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_LOCK_PI`")]
+///
+/// `flags` is or'ed into `futex_op`. `timeout` is absolute and measured against
+/// `CLOCK_REALTIME`.
+pub fn futex_lock_pi(
+    uaddr: &AtomicU32,
+    timeout: Option<&c::timespec>,
+    flags: c::c_int,
+) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_LOCK_PI | flags,
+            0,
+            timeout_ptr(timeout),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_LOCK_PI2`")]
+///
+/// `flags` is or'ed into `futex_op`. `timeout` is absolute and measured against
+/// `CLOCK_MONOTONIC` unless `flags` contains `FUTEX_CLOCK_REALTIME`.
+pub fn futex_lock_pi2(
+    uaddr: &AtomicU32,
+    timeout: Option<&c::timespec>,
+    flags: c::c_int,
+) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_LOCK_PI2 | flags,
+            0,
+            timeout_ptr(timeout),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_TRYLOCK_PI`")]
+///
+/// `flags` is or'ed into `futex_op`.
+pub fn futex_trylock_pi(uaddr: &AtomicU32, flags: c::c_int) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_TRYLOCK_PI | flags,
+            0,
+            ptr::null(),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_UNLOCK_PI`")]
+///
+/// `flags` is or'ed into `futex_op`.
+pub fn futex_unlock_pi(uaddr: &AtomicU32, flags: c::c_int) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_UNLOCK_PI | flags,
+            0,
+            ptr::null(),
+            ptr::null_mut(),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_WAIT_REQUEUE_PI`")]
+///
+/// `flags` is or'ed into `futex_op`. `timeout` is absolute.
+pub fn futex_wait_requeue_pi(
+    uaddr: &AtomicU32,
+    val: u32,
+    timeout: Option<&c::timespec>,
+    uaddr2: &AtomicU32,
+    flags: c::c_int,
+) -> Result<()> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_WAIT_REQUEUE_PI | flags,
+            val,
+            timeout_ptr(timeout),
+            self::uaddr(uaddr2),
+            0,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("futex(2) with futex_op = `FUTEX_CMP_REQUEUE_PI`")]
+///
+/// `flags` is or'ed into `futex_op`. Returns the number of woken and requeued waiters.
+pub fn futex_cmp_requeue_pi(
+    uaddr: &AtomicU32,
+    nr_wake: c::c_int,
+    uaddr2: &AtomicU32,
+    nr_requeue: c::c_int,
+    val3: u32,
+    flags: c::c_int,
+) -> Result<c::c_int> {
+    let res = unsafe {
+        c::futex(
+            self::uaddr(uaddr),
+            c::FUTEX_CMP_REQUEUE_PI | flags,
+            nr_wake as u32,
+            nr_requeue as usize as *const _,
+            self::uaddr(uaddr2),
+            val3,
+        )
+    };
+    map_err!(res)
+}
+
+/// Creates a `futex_waitv` entry for a 32-bit futex
+///
+/// `flags` is or'ed into `FUTEX_32`, e.g. `FUTEX2_PRIVATE`.
+pub fn futex_waitv_entry(uaddr: &AtomicU32, val: u32, flags: u32) -> c::futex_waitv {
+    c::futex_waitv {
+        val: val as u64,
+        uaddr: self::uaddr(uaddr) as usize as u64,
+        flags: c::FUTEX_32 | flags,
+        __reserved: 0,
+    }
+}
+
+#[man(futex_waitv(2))]
+///
+/// `timeout` is absolute and measured against `clockid` which must be
+/// `CLOCK_MONOTONIC` or `CLOCK_REALTIME`. Returns the index of the woken futex.
+///
+/// Use `futex_waitv_entry` to create the entries.
+pub fn futex_waitv(
+    waiters: &[c::futex_waitv],
+    flags: c::c_uint,
+    timeout: Option<&c::timespec>,
+    clockid: c::clockid_t,
+) -> Result<usize> {
+    let nr = match c::c_uint::try_from(waiters.len()) {
+        Ok(nr) => nr,
+        _ => return einval(),
+    };
+    let timeout = timeout.map(|t| c::__kernel_timespec {
+        tv_sec: t.tv_sec.into(),
+        tv_nsec: t.tv_nsec.into(),
+    });
+    let timeout = timeout
+        .as_ref()
+        .map(|t| t as *const _)
+        .unwrap_or(ptr::null());
+    let res = unsafe {
+        c::futex_waitv(waiters.as_ptr() as *mut _, nr, flags, timeout, clockid)
+    };
+    map_err!(res).map(|v| v as usize)
+}
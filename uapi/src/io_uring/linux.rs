@@ -0,0 +1,283 @@
+use crate::*;
+use std::{
+    mem, ptr,
+    sync::atomic::{
+        fence, AtomicU32,
+        Ordering::{Acquire, Relaxed, Release, SeqCst},
+    },
+};
+
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+const NSIG_BYTES: usize = 128 / 8;
+#[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+const NSIG_BYTES: usize = 64 / 8;
+
+#[man(io_uring_setup(2))]
+pub fn io_uring_setup(entries: u32, params: &mut c::io_uring_params) -> Result<OwnedFd> {
+    let res = unsafe { c::io_uring_setup(entries, params) };
+    map_err!(res).map(OwnedFd::new)
+}
+
+#[man(io_uring_enter(2))]
+///
+/// This function returns `libc::EINVAL` if `flags` contains `IORING_ENTER_EXT_ARG`.
+pub fn io_uring_enter(
+    fd: c::c_int,
+    to_submit: u32,
+    min_complete: u32,
+    flags: u32,
+    sig: Option<&c::sigset_t>,
+) -> Result<u32> {
+    if flags & c::IORING_ENTER_EXT_ARG != 0 {
+        return einval();
+    }
+    let (sig, sigsz) = match sig {
+        Some(sig) => (sig as *const _ as *const c::c_void, NSIG_BYTES),
+        _ => (ptr::null(), 0),
+    };
+    let res =
+        unsafe { c::io_uring_enter(fd, to_submit, min_complete, flags, sig, sigsz) };
+    map_err!(res).map(|v| v as u32)
+}
+
+#[man(io_uring_register(2))]
+///
+/// # Safety
+///
+/// `arg` and `nr_args` must be valid for `opcode`. Some opcodes, e.g.
+/// `IORING_REGISTER_BUFFERS`, allow the kernel to access the memory described by `arg`
+/// after this function returns.
+///
+/// Consult the upstream documentation.
+pub unsafe fn io_uring_register(
+    fd: c::c_int,
+    opcode: c::c_uint,
+    arg: *mut c::c_void,
+    nr_args: c::c_uint,
+) -> Result<c::c_int> {
+    let res = c::io_uring_register(fd, opcode, arg, nr_args);
+    map_err!(res)
+}
+
+fn map_ring(fd: c::c_int, len: usize, offset: c::off_t) -> Result<Mmap> {
+    unsafe {
+        Mmap::new(
+            len,
+            c::PROT_READ | c::PROT_WRITE,
+            c::MAP_SHARED | c::MAP_POPULATE,
+            fd,
+            offset,
+        )
+    }
+}
+
+unsafe fn at<T>(map: &Mmap, offset: u32) -> *mut T {
+    (map.as_ptr() as *mut u8).add(offset as usize) as *mut T
+}
+
+/// An io_uring instance with its rings mapped into memory
+///
+/// Submission queue entries are added with `push_sqe` and completion queue entries are
+/// removed with `pop_cqe`. The ring indices are managed by this type.
+///
+/// Upon `Drop`, the rings are unmapped and the file descriptor is closed.
+pub struct IoUring {
+    params: c::io_uring_params,
+    sq_head: *const AtomicU32,
+    sq_tail: *const AtomicU32,
+    sq_flags: *const AtomicU32,
+    sq_mask: u32,
+    sq_array: *mut u32,
+    sqes: *mut u8,
+    sqe_size: usize,
+    cq_head: *const AtomicU32,
+    cq_tail: *const AtomicU32,
+    cq_mask: u32,
+    cqes: *const u8,
+    cqe_size: usize,
+    _sq_ring: Mmap,
+    _cq_ring: Option<Mmap>,
+    _sqes: Mmap,
+    fd: OwnedFd,
+}
+
+unsafe impl Send for IoUring {
+}
+
+impl IoUring {
+    /// Creates a new io_uring instance with `io_uring_setup` and maps its rings
+    ///
+    /// `params` is updated by the kernel as documented for `io_uring_setup`.
+    ///
+    /// Returns `libc::EINVAL` if `params.flags` contains `IORING_SETUP_NO_MMAP`.
+    pub fn new(entries: u32, params: &mut c::io_uring_params) -> Result<IoUring> {
+        if params.flags & c::IORING_SETUP_NO_MMAP != 0 {
+            return einval();
+        }
+        let fd = io_uring_setup(entries, params)?;
+        let p = *params;
+
+        let sqe_size = match p.flags & c::IORING_SETUP_SQE128 {
+            0 => mem::size_of::<c::io_uring_sqe>(),
+            _ => 2 * mem::size_of::<c::io_uring_sqe>(),
+        };
+        let cqe_size = match p.flags & c::IORING_SETUP_CQE32 {
+            0 => mem::size_of::<c::io_uring_cqe>(),
+            _ => 2 * mem::size_of::<c::io_uring_cqe>(),
+        };
+        let mut sq_ring_len = match p.flags & c::IORING_SETUP_NO_SQARRAY {
+            0 => p.sq_off.array as usize + p.sq_entries as usize * mem::size_of::<u32>(),
+            _ => p.sq_off.dropped as usize + mem::size_of::<u32>(),
+        };
+        let cq_ring_len = p.cq_off.cqes as usize + p.cq_entries as usize * cqe_size;
+        let single_mmap = p.features & c::IORING_FEAT_SINGLE_MMAP != 0;
+        if single_mmap {
+            sq_ring_len = sq_ring_len.max(cq_ring_len);
+        }
+
+        let sq_ring = map_ring(*fd, sq_ring_len, c::IORING_OFF_SQ_RING)?;
+        let cq_ring = match single_mmap {
+            true => None,
+            false => Some(map_ring(*fd, cq_ring_len, c::IORING_OFF_CQ_RING)?),
+        };
+        let sqes = map_ring(*fd, p.sq_entries as usize * sqe_size, c::IORING_OFF_SQES)?;
+
+        unsafe {
+            let cq = cq_ring.as_ref().unwrap_or(&sq_ring);
+            Ok(IoUring {
+                params: p,
+                sq_head: at(&sq_ring, p.sq_off.head),
+                sq_tail: at(&sq_ring, p.sq_off.tail),
+                sq_flags: at(&sq_ring, p.sq_off.flags),
+                sq_mask: *at::<u32>(&sq_ring, p.sq_off.ring_mask),
+                sq_array: match p.flags & c::IORING_SETUP_NO_SQARRAY {
+                    0 => at(&sq_ring, p.sq_off.array),
+                    _ => ptr::null_mut(),
+                },
+                sqes: sqes.as_ptr() as *mut u8,
+                sqe_size,
+                cq_head: at(cq, p.cq_off.head),
+                cq_tail: at(cq, p.cq_off.tail),
+                cq_mask: *at::<u32>(cq, p.cq_off.ring_mask),
+                cqes: at(cq, p.cq_off.cqes),
+                cqe_size,
+                _sq_ring: sq_ring,
+                _cq_ring: cq_ring,
+                _sqes: sqes,
+                fd,
+            })
+        }
+    }
+
+    /// Returns the io_uring file descriptor
+    pub fn fd(&self) -> c::c_int {
+        *self.fd
+    }
+
+    /// Returns the parameters returned by `io_uring_setup`
+    pub fn params(&self) -> &c::io_uring_params {
+        &self.params
+    }
+
+    /// Returns the number of submission queue entries that can be pushed
+    pub fn sq_space_left(&self) -> u32 {
+        unsafe {
+            let head = (*self.sq_head).load(Acquire);
+            let tail = (*self.sq_tail).load(Relaxed);
+            self.params.sq_entries - tail.wrapping_sub(head)
+        }
+    }
+
+    /// Returns the number of pushed submission queue entries that have not yet been
+    /// consumed by the kernel
+    pub fn sq_pending(&self) -> u32 {
+        unsafe {
+            let head = (*self.sq_head).load(Acquire);
+            let tail = (*self.sq_tail).load(Relaxed);
+            tail.wrapping_sub(head)
+        }
+    }
+
+    /// Appends an entry to the submission queue
+    ///
+    /// The entry is not submitted until `submit` or `submit_and_wait` is called unless
+    /// the ring was created with `IORING_SETUP_SQPOLL`. If the ring was created with
+    /// `IORING_SETUP_SQE128`, the second half of the entry is zeroed.
+    ///
+    /// Returns `libc::EBUSY` if the submission queue is full.
+    ///
+    /// # Safety
+    ///
+    /// The kernel will perform the operation described by `sqe` asynchronously. All
+    /// resources referenced by `sqe`, e.g. buffers pointed to by `sqe.addr`, must remain
+    /// valid until the corresponding completion queue entry has been popped.
+    pub unsafe fn push_sqe(&mut self, sqe: &c::io_uring_sqe) -> Result<()> {
+        if self.sq_space_left() == 0 {
+            return Err(Errno(c::EBUSY));
+        }
+        let tail = (*self.sq_tail).load(Relaxed);
+        let idx = tail & self.sq_mask;
+        let slot = self.sqes.add(idx as usize * self.sqe_size);
+        ptr::write(slot as *mut c::io_uring_sqe, *sqe);
+        let sqe_len = mem::size_of::<c::io_uring_sqe>();
+        ptr::write_bytes(slot.add(sqe_len), 0, self.sqe_size - sqe_len);
+        if !self.sq_array.is_null() {
+            *self.sq_array.add(idx as usize) = idx;
+        }
+        (*self.sq_tail).store(tail.wrapping_add(1), Release);
+        Ok(())
+    }
+
+    /// Shortcut for `submit_and_wait(0)`
+    pub fn submit(&mut self) -> Result<u32> {
+        self.submit_and_wait(0)
+    }
+
+    /// Submits all pending entries and waits for `want` completions
+    ///
+    /// Returns the number of submitted entries.
+    pub fn submit_and_wait(&mut self, want: u32) -> Result<u32> {
+        let pending = self.sq_pending();
+        let mut flags = 0;
+        if want > 0 {
+            flags |= c::IORING_ENTER_GETEVENTS;
+        }
+        if self.params.flags & c::IORING_SETUP_SQPOLL != 0 {
+            fence(SeqCst);
+            let sq_flags = unsafe { (*self.sq_flags).load(Relaxed) };
+            if sq_flags & c::IORING_SQ_NEED_WAKEUP != 0 {
+                flags |= c::IORING_ENTER_SQ_WAKEUP;
+            } else if want == 0 {
+                return Ok(pending);
+            }
+        }
+        io_uring_enter(*self.fd, pending, want, flags, None)
+    }
+
+    /// Returns the number of entries in the completion queue
+    pub fn cq_ready(&self) -> u32 {
+        unsafe {
+            let head = (*self.cq_head).load(Relaxed);
+            let tail = (*self.cq_tail).load(Acquire);
+            tail.wrapping_sub(head)
+        }
+    }
+
+    /// Removes an entry from the completion queue
+    ///
+    /// If the ring was created with `IORING_SETUP_CQE32`, the second half of the entry
+    /// is discarded.
+    pub fn pop_cqe(&mut self) -> Option<c::io_uring_cqe> {
+        unsafe {
+            let head = (*self.cq_head).load(Relaxed);
+            let tail = (*self.cq_tail).load(Acquire);
+            if head == tail {
+                return None;
+            }
+            let idx = (head & self.cq_mask) as usize;
+            let cqe = ptr::read(self.cqes.add(idx * self.cqe_size) as *const _);
+            (*self.cq_head).store(head.wrapping_add(1), Release);
+            Some(cqe)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::*;
+use std::{mem, ptr};
+
+#[man(landlock_create_ruleset(2))]
+pub fn landlock_create_ruleset(
+    attr: &c::landlock_ruleset_attr,
+    flags: u32,
+) -> Result<OwnedFd> {
+    let res = unsafe { c::landlock_create_ruleset(attr, mem::size_of_val(attr), flags) };
+    map_err!(res).map(OwnedFd::new)
+}
+
+#[man("landlock_create_ruleset(2) with flags = `LANDLOCK_CREATE_RULESET_VERSION`")]
+///
+/// Returns the highest supported Landlock ABI version.
+pub fn landlock_create_ruleset_version() -> Result<c::c_int> {
+    let res = unsafe {
+        c::landlock_create_ruleset(ptr::null(), 0, c::LANDLOCK_CREATE_RULESET_VERSION)
+    };
+    map_err!(res)
+}
+
+#[man("landlock_add_rule(2) with rule_type = `LANDLOCK_RULE_PATH_BENEATH`")]
+pub fn landlock_add_rule_path_beneath(
+    ruleset_fd: c::c_int,
+    attr: &c::landlock_path_beneath_attr,
+    flags: u32,
+) -> Result<()> {
+    let res = unsafe {
+        c::landlock_add_rule(
+            ruleset_fd,
+            c::LANDLOCK_RULE_PATH_BENEATH,
+            attr as *const _ as *const _,
+            flags,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man("landlock_add_rule(2) with rule_type = `LANDLOCK_RULE_NET_PORT`")]
+pub fn landlock_add_rule_net_port(
+    ruleset_fd: c::c_int,
+    attr: &c::landlock_net_port_attr,
+    flags: u32,
+) -> Result<()> {
+    let res = unsafe {
+        c::landlock_add_rule(
+            ruleset_fd,
+            c::LANDLOCK_RULE_NET_PORT,
+            attr as *const _ as *const _,
+            flags,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man(landlock_restrict_self(2))]
+pub fn landlock_restrict_self(ruleset_fd: c::c_int, flags: u32) -> Result<()> {
+    let res = unsafe { c::landlock_restrict_self(ruleset_fd, flags) };
+    map_err!(res).map(drop)
+}
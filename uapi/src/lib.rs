@@ -16,9 +16,10 @@
 extern crate proc; // https://github.com/rust-lang/rust/issues/64450
 
 pub use crate::{
-    dir::*, errno::*, fcntl::*, fd::*, file::*, ioctl::*, mount::*, other::*, pod::*,
-    poll::*, process::*, result::*, sched::*, signal::*, socket::*, timer::*, uninit::*,
-    ustr::*, util::*,
+    bpf::*, capability::*, dir::*, errno::*, fcntl::*, fd::*, file::*, futex::*,
+    io_uring::*, ioctl::*, landlock::*, mman::*, mount::*, other::*, pod::*, poll::*,
+    process::*, ptrace::*, result::*, sched::*, seccomp::*, signal::*, socket::*,
+    timer::*, uninit::*, ustr::*, util::*,
 };
 
 use proc::*;
@@ -28,19 +29,27 @@ pub mod docs;
 
 #[macro_use]
 mod macros;
+mod bpf;
+mod capability;
 mod dir;
 mod errno;
 mod fcntl;
 mod fd;
 mod file;
+mod futex;
+mod io_uring;
 mod ioctl;
+mod landlock;
+mod mman;
 mod mount;
 mod other;
 mod pod;
 mod poll;
 mod process;
+mod ptrace;
 mod result;
 mod sched;
+mod seccomp;
 mod signal;
 mod socket;
 mod timer;
@@ -0,0 +1,98 @@
+use crate::*;
+use std::ptr;
+
+#[man(mremap(2))]
+///
+/// `new_address` is only used if `flags` contains `MREMAP_FIXED`.
+///
+/// # Safety
+///
+/// The old range must not be accessed after this function returns unless `flags`
+/// contains `MREMAP_DONTUNMAP`. If `flags` contains `MREMAP_FIXED`, existing mappings
+/// at `new_address` are replaced.
+pub unsafe fn mremap(
+    old_address: *mut c::c_void,
+    old_size: usize,
+    new_size: usize,
+    flags: c::c_int,
+    new_address: *mut c::c_void,
+) -> Result<*mut c::c_void> {
+    let res = c::mremap(old_address, old_size, new_size, flags, new_address);
+    if res == c::MAP_FAILED {
+        Err(Errno::default())
+    } else {
+        Ok(res)
+    }
+}
+
+#[man(mlock2(2))]
+///
+/// # Safety
+///
+/// Consult the upstream documentation.
+pub unsafe fn mlock2(addr: *const c::c_void, len: usize, flags: c::c_uint) -> Result<()> {
+    let res = c::syscall(c::SYS_mlock2, addr as usize, len, flags as usize);
+    map_err!(res).map(drop)
+}
+
+#[man(mincore(2))]
+///
+/// This function returns `libc::EINVAL` if `vec` is too small for `len`.
+///
+/// # Safety
+///
+/// Consult the upstream documentation.
+pub unsafe fn mincore(addr: *mut c::c_void, len: usize, vec: &mut [u8]) -> Result<()> {
+    let page_size = sysconf(c::_SC_PAGESIZE)? as usize;
+    if vec.len() < len.div_ceil(page_size) {
+        return einval();
+    }
+    let res = c::mincore(addr, len, vec.as_mut_ptr());
+    map_err!(res).map(drop)
+}
+
+#[man(process_madvise(2))]
+pub fn process_madvise<T: MaybeUninitIovec + ?Sized>(
+    pidfd: c::c_int,
+    iovec: &T,
+    advice: c::c_int,
+    flags: c::c_uint,
+) -> Result<usize> {
+    let iovec = iovec.as_iovec();
+    let res = unsafe {
+        c::syscall(
+            c::SYS_process_madvise,
+            pidfd as usize,
+            black_box_id(iovec.as_ptr()) as *const c::iovec as usize,
+            iovec.len(),
+            advice as usize,
+            flags as usize,
+        )
+    };
+    map_err!(res).map(|v| v as usize)
+}
+
+#[man(process_mrelease(2))]
+pub fn process_mrelease(pidfd: c::c_int, flags: c::c_uint) -> Result<()> {
+    let res =
+        unsafe { c::syscall(c::SYS_process_mrelease, pidfd as usize, flags as usize) };
+    map_err!(res).map(drop)
+}
+
+impl Mmap {
+    /// Resizes the mapping with `mremap`
+    ///
+    /// If `flags` contains `MREMAP_MAYMOVE`, the mapping might be moved.
+    ///
+    /// This function returns `libc::EINVAL` if `flags` contains `MREMAP_FIXED` or
+    /// `MREMAP_DONTUNMAP`.
+    pub fn mremap(&mut self, new_len: usize, flags: c::c_int) -> Result<()> {
+        if flags & (c::MREMAP_FIXED | c::MREMAP_DONTUNMAP) != 0 {
+            return einval();
+        }
+        self.ptr =
+            unsafe { mremap(self.ptr, self.len, new_len, flags, ptr::null_mut())? };
+        self.len = new_len;
+        Ok(())
+    }
+}
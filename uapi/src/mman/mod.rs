@@ -0,0 +1,227 @@
+use crate::*;
+use std::{
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+        pub use linux::*;
+    }
+}
+
+#[man(mmap(2))]
+///
+/// # Safety
+///
+/// If `flags` contains `MAP_FIXED`, existing mappings in the range are replaced.
+///
+/// Consult the upstream documentation.
+pub unsafe fn mmap(
+    addr: *mut c::c_void,
+    len: usize,
+    prot: c::c_int,
+    flags: c::c_int,
+    fd: c::c_int,
+    offset: c::off_t,
+) -> Result<*mut c::c_void> {
+    let res = c::mmap(addr, len, prot, flags, fd, offset);
+    if res == c::MAP_FAILED {
+        Err(Errno::default())
+    } else {
+        Ok(res)
+    }
+}
+
+#[man(munmap(2))]
+///
+/// # Safety
+///
+/// The range must not be accessed after this function returns.
+pub unsafe fn munmap(addr: *mut c::c_void, len: usize) -> Result<()> {
+    let res = c::munmap(addr, len);
+    map_err!(res).map(drop)
+}
+
+#[man(mprotect(2))]
+///
+/// # Safety
+///
+/// Existing references into the range must remain valid under the new protection.
+pub unsafe fn mprotect(addr: *mut c::c_void, len: usize, prot: c::c_int) -> Result<()> {
+    let res = c::mprotect(addr, len, prot);
+    map_err!(res).map(drop)
+}
+
+#[man(madvise(2))]
+///
+/// # Safety
+///
+/// Some advice values, e.g. `MADV_DONTNEED`, change the contents of the range.
+pub unsafe fn madvise(addr: *mut c::c_void, len: usize, advice: c::c_int) -> Result<()> {
+    let res = c::madvise(addr, len, advice);
+    map_err!(res).map(drop)
+}
+
+#[man(msync(2))]
+///
+/// # Safety
+///
+/// Consult the upstream documentation.
+pub unsafe fn msync(addr: *mut c::c_void, len: usize, flags: c::c_int) -> Result<()> {
+    let res = c::msync(addr, len, flags);
+    map_err!(res).map(drop)
+}
+
+#[man(mlock(2))]
+///
+/// # Safety
+///
+/// Consult the upstream documentation.
+pub unsafe fn mlock(addr: *const c::c_void, len: usize) -> Result<()> {
+    let res = c::mlock(addr, len);
+    map_err!(res).map(drop)
+}
+
+#[man(munlock(2))]
+///
+/// # Safety
+///
+/// Consult the upstream documentation.
+pub unsafe fn munlock(addr: *const c::c_void, len: usize) -> Result<()> {
+    let res = c::munlock(addr, len);
+    map_err!(res).map(drop)
+}
+
+#[man(mlockall(2))]
+pub fn mlockall(flags: c::c_int) -> Result<()> {
+    let res = unsafe { c::mlockall(flags) };
+    map_err!(res).map(drop)
+}
+
+#[man(munlockall(2))]
+pub fn munlockall() -> Result<()> {
+    let res = unsafe { c::munlockall() };
+    map_err!(res).map(drop)
+}
+
+/// An owned memory mapping
+///
+/// Upon `Drop`, the mapping is unmapped. Errors from `munmap()` are ignored.
+///
+/// The mapped memory can be accessed via deref: `&*self` and `&mut *self`.
+#[derive(Debug)]
+pub struct Mmap {
+    ptr: *mut c::c_void,
+    len: usize,
+}
+
+unsafe impl Send for Mmap {
+}
+
+unsafe impl Sync for Mmap {
+}
+
+impl Mmap {
+    /// Creates a new mapping with `mmap` and `addr = NULL`
+    ///
+    /// # Safety
+    ///
+    /// The memory is accessed via deref. `prot` must contain `PROT_READ` and, if the
+    /// memory is accessed mutably, `PROT_WRITE`. For file-backed mappings, the file must
+    /// not be truncated and, if `flags` contains `MAP_SHARED`, the memory must not be
+    /// modified by other mappings while references returned by deref are alive.
+    pub unsafe fn new(
+        len: usize,
+        prot: c::c_int,
+        flags: c::c_int,
+        fd: c::c_int,
+        offset: c::off_t,
+    ) -> Result<Mmap> {
+        let ptr = mmap(ptr::null_mut(), len, prot, flags, fd, offset)?;
+        Ok(Mmap { ptr, len })
+    }
+
+    /// Creates a new private, anonymous, readable and writable mapping
+    pub fn anonymous(len: usize) -> Result<Mmap> {
+        unsafe {
+            Mmap::new(
+                len,
+                c::PROT_READ | c::PROT_WRITE,
+                c::MAP_PRIVATE | c::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        }
+    }
+
+    /// Takes ownership of an existing mapping
+    ///
+    /// # Safety
+    ///
+    /// `ptr` and `len` must describe a mapping that is not owned by anything else. The
+    /// requirements of `Mmap::new` apply.
+    pub unsafe fn from_raw(ptr: *mut c::c_void, len: usize) -> Mmap {
+        Mmap { ptr, len }
+    }
+
+    /// Returns the address and length of the mapping and does not run `Drop`
+    pub fn unwrap(self) -> (*mut c::c_void, usize) {
+        let res = (self.ptr, self.len);
+        std::mem::forget(self);
+        res
+    }
+
+    /// Returns the address of the mapping
+    pub fn as_ptr(&self) -> *mut c::c_void {
+        self.ptr
+    }
+
+    /// Returns the length of the mapping
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the mapping has length `0`
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Shortcut for `msync(self.as_ptr(), self.len(), flags)`
+    pub fn msync(&self, flags: c::c_int) -> Result<()> {
+        unsafe { msync(self.ptr, self.len, flags) }
+    }
+
+    /// Shortcut for `mlock(self.as_ptr(), self.len())`
+    pub fn mlock(&self) -> Result<()> {
+        unsafe { mlock(self.ptr, self.len) }
+    }
+
+    /// Shortcut for `munlock(self.as_ptr(), self.len())`
+    pub fn munlock(&self) -> Result<()> {
+        unsafe { munlock(self.ptr, self.len) }
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl DerefMut for Mmap {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { slice::from_raw_parts_mut(self.ptr as *mut u8, self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        unsafe {
+            c::munmap(self.ptr, self.len);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use std::{convert::TryFrom, mem::MaybeUninit, ptr};
+use std::{convert::TryFrom, ffi::CStr, mem, mem::MaybeUninit, ops::Deref, ptr};
 
 #[man(mount(2))]
 pub fn mount<'a, 'b, 'c, 'd>(
@@ -214,3 +214,207 @@ pub fn fspick<'a>(
     let res = unsafe { c::fspick(dfd, path.as_ptr(), flags) };
     map_err!(res).map(OwnedFd::new)
 }
+
+#[man(mount_setattr(2))]
+///
+/// `attr.propagation` is one of `MS_PRIVATE`, `MS_SLAVE`, `MS_SHARED`, `MS_UNBINDABLE`,
+/// or `0`. If `attr.attr_set` contains `MOUNT_ATTR_IDMAP`, `attr.userns_fd` is a file
+/// descriptor referring to a user namespace.
+pub fn mount_setattr<'a>(
+    dirfd: c::c_int,
+    path: impl IntoUstr<'a>,
+    flags: c::c_uint,
+    attr: &c::mount_attr,
+) -> Result<()> {
+    let path = path.into_ustr();
+    let mut attr = *attr;
+    let res = unsafe {
+        c::mount_setattr(
+            dirfd,
+            path.as_ptr(),
+            flags,
+            &mut attr,
+            mem::size_of_val(&attr),
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+#[man(listmount(2))]
+///
+/// `req.size` is ignored. Returns the initialized prefix of `mnt_ids`.
+pub fn listmount<'a>(
+    req: &c::mnt_id_req,
+    mnt_ids: &'a mut [u64],
+    flags: c::c_uint,
+) -> Result<&'a mut [u64]> {
+    let mut req = *req;
+    req.size = mem::size_of_val(&req) as u32;
+    let res = unsafe { c::listmount(&req, mnt_ids.as_mut_ptr(), mnt_ids.len(), flags) };
+    map_err!(res).map(move |n| &mut mnt_ids[..n as usize])
+}
+
+#[man(statmount(2))]
+///
+/// `req.size` is ignored. `buf` should be large enough to hold `c::statmount` and the
+/// requested strings. Otherwise `libc::EOVERFLOW` is returned.
+pub fn statmount<'a, T: Pod + ?Sized>(
+    req: &c::mnt_id_req,
+    buf: &'a mut T,
+    flags: c::c_uint,
+) -> Result<Statmount<'a>> {
+    let mut req = *req;
+    req.size = mem::size_of_val(&req) as u32;
+    unsafe {
+        let buf = as_maybe_uninit_bytes_mut2(buf);
+        let res = c::statmount(&req, buf.as_mut_ptr() as *mut _, buf.len(), flags);
+        map_err!(res)?;
+        let size = match buf.get(..4) {
+            Some(size) => pod_read_init::<u32, _>(size.slice_assume_init_ref())? as usize,
+            _ => return einval(),
+        };
+        if size < mem::size_of::<c::statmount>() {
+            return einval();
+        }
+        let buf = match buf.get(..size) {
+            Some(buf) => buf.slice_assume_init_ref(),
+            _ => return einval(),
+        };
+        Ok(Statmount {
+            sm: pod_read_init(buf)?,
+            str: &buf[mem::size_of::<c::statmount>()..],
+        })
+    }
+}
+
+/// Wrapper for `libc::statmount`
+///
+/// The string fields of `libc::statmount` are offsets into the variable-length string
+/// area following the structure. The methods of this type decode them. A method
+/// returns `None` if the corresponding `STATMOUNT_*` flag is not set in `mask` or if the
+/// offset does not point to a nul-terminated string.
+pub struct Statmount<'a> {
+    sm: c::statmount,
+    str: &'a [u8],
+}
+
+impl<'a> Statmount<'a> {
+    fn str_at(&self, flag: u64, offset: u32) -> Option<&'a CStr> {
+        if self.sm.mask & flag == 0 {
+            return None;
+        }
+        let s = self.str.get(offset as usize..)?;
+        let len = s.iter().position(|&b| b == 0)?;
+        CStr::from_bytes_with_nul(&s[..=len]).ok()
+    }
+
+    fn str_array(&self, flag: u64, offset: u32, num: u32) -> Option<StatmountStrs<'a>> {
+        if self.sm.mask & flag == 0 {
+            return None;
+        }
+        Some(StatmountStrs {
+            str: self.str.get(offset as usize..)?,
+            num,
+        })
+    }
+
+    /// Returns the `fs_type` field
+    pub fn fs_type(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_FS_TYPE, self.sm.fs_type)
+    }
+
+    /// Returns the `fs_subtype` field
+    pub fn fs_subtype(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_FS_SUBTYPE, self.sm.fs_subtype)
+    }
+
+    /// Returns the `sb_source` field
+    pub fn sb_source(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_SB_SOURCE, self.sm.sb_source)
+    }
+
+    /// Returns the `mnt_root` field
+    pub fn mnt_root(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_MNT_ROOT, self.sm.mnt_root)
+    }
+
+    /// Returns the `mnt_point` field
+    pub fn mnt_point(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_MNT_POINT, self.sm.mnt_point)
+    }
+
+    /// Returns the `mnt_opts` field
+    pub fn mnt_opts(&self) -> Option<&'a CStr> {
+        self.str_at(c::STATMOUNT_MNT_OPTS, self.sm.mnt_opts)
+    }
+
+    /// Returns the `opt_num` strings of the `opt_array` field
+    pub fn opt_array(&self) -> Option<StatmountStrs<'a>> {
+        self.str_array(c::STATMOUNT_OPT_ARRAY, self.sm.opt_array, self.sm.opt_num)
+    }
+
+    /// Returns the `opt_sec_num` strings of the `opt_sec_array` field
+    pub fn opt_sec_array(&self) -> Option<StatmountStrs<'a>> {
+        self.str_array(
+            c::STATMOUNT_OPT_SEC_ARRAY,
+            self.sm.opt_sec_array,
+            self.sm.opt_sec_num,
+        )
+    }
+
+    /// Returns the `mnt_uidmap_num` strings of the `mnt_uidmap` field
+    pub fn mnt_uidmap(&self) -> Option<StatmountStrs<'a>> {
+        self.str_array(
+            c::STATMOUNT_MNT_UIDMAP,
+            self.sm.mnt_uidmap,
+            self.sm.mnt_uidmap_num,
+        )
+    }
+
+    /// Returns the `mnt_gidmap_num` strings of the `mnt_gidmap` field
+    pub fn mnt_gidmap(&self) -> Option<StatmountStrs<'a>> {
+        self.str_array(
+            c::STATMOUNT_MNT_GIDMAP,
+            self.sm.mnt_gidmap,
+            self.sm.mnt_gidmap_num,
+        )
+    }
+}
+
+impl Deref for Statmount<'_> {
+    type Target = c::statmount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sm
+    }
+}
+
+/// Iterator over a nul-separated string array of `libc::statmount`
+///
+/// The iterator stops early if the string area is shorter than indicated by the
+/// corresponding `*_num` field.
+pub struct StatmountStrs<'a> {
+    str: &'a [u8],
+    num: u32,
+}
+
+impl<'a> Iterator for StatmountStrs<'a> {
+    type Item = &'a CStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num == 0 {
+            return None;
+        }
+        let len = match self.str.iter().position(|&b| b == 0) {
+            Some(len) => len,
+            None => {
+                self.num = 0;
+                return None;
+            }
+        };
+        let (s, rest) = self.str.split_at(len + 1);
+        self.str = rest;
+        self.num -= 1;
+        CStr::from_bytes_with_nul(s).ok()
+    }
+}
@@ -37,6 +37,12 @@ pub fn memfd_create<'a>(name: impl IntoUstr<'a>, flags: c::c_uint) -> Result<Own
     map_err!(res).map(|val| OwnedFd::new(val as _))
 }
 
+#[man(memfd_secret(2))]
+pub fn memfd_secret(flags: c::c_uint) -> Result<OwnedFd> {
+    let res = unsafe { c::syscall(c::SYS_memfd_secret, flags as usize) };
+    map_err!(res).map(|val| OwnedFd::new(val as _))
+}
+
 #[man(sysinfo(2))]
 pub fn sysinfo() -> Result<c::sysinfo> {
     let mut sysinfo = MaybeUninit::uninit();
@@ -188,7 +188,29 @@ imp_pod! {
     c::signalfd_siginfo
     c::nlmsghdr
     c::nlattr
+    c::rtattr
     c::ifinfomsg
+    c::ifaddrmsg
+    c::rtmsg
+    c::ndmsg
+    c::sock_diag_req
+    c::inet_diag_sockid
+    c::inet_diag_req_v2
+    c::inet_diag_msg
+    c::inet_diag_meminfo
+    c::unix_diag_req
+    c::unix_diag_msg
+    c::unix_diag_vfs
+    c::unix_diag_rqlen
+    c::mmsghdr
+    c::__user_cap_header_struct
+    c::__user_cap_data_struct
+    c::sock_filter
+    c::seccomp_data
+    c::seccomp_notif
+    c::seccomp_notif_resp
+    c::seccomp_notif_addfd
+    c::seccomp_notif_sizes
     c::input_event
     c::open_how
     c::sched_attr
@@ -198,6 +220,21 @@ imp_pod! {
     c::itimerspec
     c::itimerval
     c::epoll_event
+    c::io_uring_params
+    c::io_uring_sqe
+    c::io_uring_cqe
+    c::landlock_ruleset_attr
+    c::landlock_path_beneath_attr
+    c::landlock_net_port_attr
+    c::mount_attr
+    c::mnt_id_req
+    c::statmount
+    c::statx
+    c::statx_timestamp
+    c::tcp_info
+    c::clone_args
+    c::futex_waitv
+    c::__kernel_timespec
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd")))]
@@ -1,4 +1,5 @@
 use crate::*;
+use std::{ffi::CStr, fmt::Write as _, mem, ptr};
 
 #[man(setns(2))]
 pub fn setns(fd: c::c_int, nstype: c::c_int) -> Result<()> {
@@ -89,3 +90,366 @@ pub fn pidfd_getfd(
     };
     map_err!(res).map(|f| OwnedFd::new(f as _))
 }
+
+#[man(clone3(2))]
+///
+/// `args` is passed to the kernel unmodified. If `args.flags` contains `CLONE_PIDFD`,
+/// `args.pidfd` must point to a `c_int` that receives the pidfd. Use `clone3_pidfd` to
+/// receive it as an `OwnedFd` instead.
+///
+/// # Safety
+///
+/// `clone3` is unsafe for the same reasons as `fork`.
+///
+/// Additionally, all pointers in `args` must be valid for the kernel to read or write.
+/// If `args.stack` is not `0` or `args.flags` contains `CLONE_VM`, the child shares or
+/// replaces the stack of the parent and must not return from this function.
+///
+/// Consult the upstream documentation.
+pub unsafe fn clone3(args: &mut c::clone_args) -> Result<c::pid_t> {
+    let res = c::clone3(args, mem::size_of_val(args));
+    map_err!(res)
+}
+
+/// Shortcut for `clone3` with `CLONE_PIDFD`
+///
+/// This function sets `CLONE_PIDFD` in `args.flags` and points `args.pidfd` at a local
+/// variable. In the parent, the returned pidfd refers to the child. In the child,
+/// `None` is returned.
+///
+/// # Safety
+///
+/// See `clone3`.
+pub unsafe fn clone3_pidfd(
+    args: &mut c::clone_args,
+) -> Result<(c::pid_t, Option<OwnedFd>)> {
+    let mut pidfd: c::c_int = -1;
+    args.flags |= c::CLONE_PIDFD as u64;
+    args.pidfd = &mut pidfd as *mut _ as u64;
+    let pid = clone3(args)?;
+    match pid {
+        0 => Ok((0, None)),
+        _ => Ok((pid, Some(OwnedFd::new(pidfd)))),
+    }
+}
+
+#[man(pidfd_send_signal(2))]
+pub fn pidfd_send_signal(
+    pidfd: c::c_int,
+    sig: c::c_int,
+    info: Option<&c::siginfo_t>,
+    flags: c::c_uint,
+) -> Result<()> {
+    let res = unsafe {
+        c::syscall(
+            c::SYS_pidfd_send_signal,
+            pidfd as usize,
+            sig as usize,
+            info.map(|i| i as *const _).unwrap_or(ptr::null()) as usize,
+            flags as usize,
+        )
+    };
+    map_err!(res).map(drop)
+}
+
+fn prctl_(
+    option: c::c_int,
+    arg2: c::c_ulong,
+    arg3: c::c_ulong,
+    arg4: c::c_ulong,
+    arg5: c::c_ulong,
+) -> Result<c::c_int> {
+    let res = unsafe { c::prctl(option, arg2, arg3, arg4, arg5) };
+    map_err!(res)
+}
+
+#[man("prctl(2) with option = `PR_SET_NO_NEW_PRIVS`")]
+pub fn prctl_set_no_new_privs() -> Result<()> {
+    prctl_(c::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_NO_NEW_PRIVS`")]
+pub fn prctl_get_no_new_privs() -> Result<c::c_int> {
+    prctl_(c::PR_GET_NO_NEW_PRIVS, 0, 0, 0, 0)
+}
+
+#[man("prctl(2) with option = `PR_SET_PDEATHSIG`")]
+pub fn prctl_set_pdeathsig(sig: c::c_int) -> Result<()> {
+    prctl_(c::PR_SET_PDEATHSIG, sig as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_PDEATHSIG`")]
+pub fn prctl_get_pdeathsig() -> Result<c::c_int> {
+    let mut sig = 0;
+    prctl_(c::PR_GET_PDEATHSIG, &mut sig as *mut c::c_int as _, 0, 0, 0)?;
+    Ok(sig)
+}
+
+#[man("prctl(2) with option = `PR_SET_NAME`")]
+///
+/// The kernel silently truncates names longer than 15 bytes.
+pub fn prctl_set_name<'a>(name: impl IntoUstr<'a>) -> Result<()> {
+    let name = name.into_ustr();
+    prctl_(c::PR_SET_NAME, name.as_ptr() as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_NAME`")]
+///
+/// This function returns `libc::ENAMETOOLONG` if the name does not fit in the supplied
+/// buffer. A buffer of 16 bytes is always sufficient.
+pub fn prctl_get_name<T: Pod + ?Sized>(buf: &mut T) -> Result<&CStr> {
+    // The kernel always writes 16 bytes including the terminating nul byte.
+    const SIZE: usize = 16;
+    let mut inner = [0u8; SIZE];
+    prctl_(c::PR_GET_NAME, inner.as_mut_ptr() as _, 0, 0, 0)?;
+    let len = match inner.iter().position(|&b| b == 0) {
+        Some(pos) => pos + 1,
+        _ => return Err(Errno(c::ENAMETOOLONG)),
+    };
+    unsafe {
+        let buf = as_maybe_uninit_bytes_mut2(buf);
+        if len > buf.len() {
+            return Err(Errno(c::ENAMETOOLONG));
+        }
+        ptr::copy_nonoverlapping(inner.as_ptr(), buf.as_mut_ptr() as *mut u8, len);
+        Ok(CStr::from_bytes_with_nul_unchecked(
+            buf[..len].slice_assume_init_ref(),
+        ))
+    }
+}
+
+#[man("prctl(2) with option = `PR_SET_CHILD_SUBREAPER`")]
+pub fn prctl_set_child_subreaper(val: c::c_int) -> Result<()> {
+    prctl_(c::PR_SET_CHILD_SUBREAPER, val as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_CHILD_SUBREAPER`")]
+pub fn prctl_get_child_subreaper() -> Result<c::c_int> {
+    let mut val = 0;
+    prctl_(
+        c::PR_GET_CHILD_SUBREAPER,
+        &mut val as *mut c::c_int as _,
+        0,
+        0,
+        0,
+    )?;
+    Ok(val)
+}
+
+#[man("prctl(2) with option = `PR_CAPBSET_DROP`")]
+pub fn prctl_capbset_drop(cap: c::c_int) -> Result<()> {
+    prctl_(c::PR_CAPBSET_DROP, cap as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_CAPBSET_READ`")]
+pub fn prctl_capbset_read(cap: c::c_int) -> Result<c::c_int> {
+    prctl_(c::PR_CAPBSET_READ, cap as _, 0, 0, 0)
+}
+
+#[man("prctl(2) with option = `PR_CAP_AMBIENT` and arg2 = `PR_CAP_AMBIENT_RAISE`")]
+pub fn prctl_cap_ambient_raise(cap: c::c_int) -> Result<()> {
+    let op = c::PR_CAP_AMBIENT_RAISE as _;
+    prctl_(c::PR_CAP_AMBIENT, op, cap as _, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_CAP_AMBIENT` and arg2 = `PR_CAP_AMBIENT_LOWER`")]
+pub fn prctl_cap_ambient_lower(cap: c::c_int) -> Result<()> {
+    let op = c::PR_CAP_AMBIENT_LOWER as _;
+    prctl_(c::PR_CAP_AMBIENT, op, cap as _, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_CAP_AMBIENT` and arg2 = `PR_CAP_AMBIENT_IS_SET`")]
+pub fn prctl_cap_ambient_is_set(cap: c::c_int) -> Result<c::c_int> {
+    let op = c::PR_CAP_AMBIENT_IS_SET as _;
+    prctl_(c::PR_CAP_AMBIENT, op, cap as _, 0, 0)
+}
+
+#[man("prctl(2) with option = `PR_CAP_AMBIENT` and arg2 = `PR_CAP_AMBIENT_CLEAR_ALL`")]
+pub fn prctl_cap_ambient_clear_all() -> Result<()> {
+    let op = c::PR_CAP_AMBIENT_CLEAR_ALL as _;
+    prctl_(c::PR_CAP_AMBIENT, op, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_SET_DUMPABLE`")]
+pub fn prctl_set_dumpable(val: c::c_int) -> Result<()> {
+    prctl_(c::PR_SET_DUMPABLE, val as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_DUMPABLE`")]
+pub fn prctl_get_dumpable() -> Result<c::c_int> {
+    prctl_(c::PR_GET_DUMPABLE, 0, 0, 0, 0)
+}
+
+#[man("prctl(2) with option = `PR_SET_KEEPCAPS`")]
+pub fn prctl_set_keepcaps(val: c::c_int) -> Result<()> {
+    prctl_(c::PR_SET_KEEPCAPS, val as _, 0, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_GET_KEEPCAPS`")]
+pub fn prctl_get_keepcaps() -> Result<c::c_int> {
+    prctl_(c::PR_GET_KEEPCAPS, 0, 0, 0, 0)
+}
+
+#[man("prctl(2) with option = `PR_SET_MM`")]
+///
+/// `option` is one of the `PR_SET_MM_*` constants except `PR_SET_MM_MAP` and
+/// `PR_SET_MM_MAP_SIZE`. Use `prctl_set_mm_map` to update all fields at once.
+///
+/// # Safety
+///
+/// Changing the memory map descriptors can break assumptions made by the allocator or
+/// the runtime, e.g., moving the program break with `PR_SET_MM_BRK`.
+pub unsafe fn prctl_set_mm(option: c::c_int, value: c::c_ulong) -> Result<()> {
+    prctl_(c::PR_SET_MM, option as _, value, 0, 0).map(drop)
+}
+
+#[man("prctl(2) with option = `PR_SET_MM` and arg2 = `PR_SET_MM_MAP`")]
+///
+/// # Safety
+///
+/// See `prctl_set_mm`. Additionally, `map.auxv` must point to `map.auxv_size` bytes.
+pub unsafe fn prctl_set_mm_map(map: &c::prctl_mm_map) -> Result<()> {
+    let op = c::PR_SET_MM_MAP as _;
+    let size = mem::size_of_val(map) as _;
+    prctl_(c::PR_SET_MM, op, map as *const _ as _, size, 0).map(drop)
+}
+
+/// A range of a uid or gid mapping of a user namespace
+///
+/// See `proc_write_uid_map`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IdMapRange {
+    /// The first id in the user namespace
+    pub inside: u32,
+    /// The first id in the user namespace of the process opening the map file
+    pub outside: u32,
+    /// The number of ids in the range
+    pub count: u32,
+}
+
+fn proc_write_file(proc_dir: c::c_int, name: &str, contents: &[u8]) -> Result<()> {
+    let fd = openat(proc_dir, name, c::O_WRONLY | c::O_CLOEXEC, 0)?;
+    // The kernel only accepts the contents in a single write at offset 0.
+    let n = write(*fd, contents)?;
+    if n != contents.len() {
+        return Err(Errno(c::EIO));
+    }
+    Ok(())
+}
+
+fn proc_write_id_map(proc_dir: c::c_int, name: &str, map: &[IdMapRange]) -> Result<()> {
+    let mut contents = String::new();
+    for range in map {
+        // writing to a string cannot fail
+        let _ = writeln!(
+            contents,
+            "{} {} {}",
+            range.inside, range.outside, range.count
+        );
+    }
+    proc_write_file(proc_dir, name, contents.as_bytes())
+}
+
+/// Writes the `uid_map` file of a process
+///
+/// `proc_dir` is a file descriptor of the `/proc/<pid>` directory of the process, e.g.,
+/// `/proc/self` or the return value of `pidfd_open_proc_dir`. All ranges are written
+/// with a single `write` as required by the kernel.
+///
+/// The errors of `openat` and `write` are returned unmodified. For example, `EPERM` is
+/// returned if the map has already been written and `EINVAL` if the ranges overlap.
+pub fn proc_write_uid_map(proc_dir: c::c_int, map: &[IdMapRange]) -> Result<()> {
+    proc_write_id_map(proc_dir, "uid_map", map)
+}
+
+/// Writes the `gid_map` file of a process
+///
+/// See `proc_write_uid_map`. Unprivileged processes must deny `setgroups` before the
+/// `gid_map` can be written. Use `proc_write_id_maps` to perform the steps in the
+/// correct order.
+pub fn proc_write_gid_map(proc_dir: c::c_int, map: &[IdMapRange]) -> Result<()> {
+    proc_write_id_map(proc_dir, "gid_map", map)
+}
+
+/// Writes the `setgroups` file of a process
+///
+/// `proc_dir` is a file descriptor of the `/proc/<pid>` directory of the process. If
+/// `allow` is `false`, `deny` is written and the process can no longer call
+/// `setgroups`.
+pub fn proc_write_setgroups(proc_dir: c::c_int, allow: bool) -> Result<()> {
+    let contents: &[u8] = match allow {
+        true => b"allow",
+        false => b"deny",
+    };
+    proc_write_file(proc_dir, "setgroups", contents)
+}
+
+/// Writes the `setgroups`, `uid_map`, and `gid_map` files of a process
+///
+/// If `deny_setgroups` is `true`, `setgroups` is denied before the maps are written.
+/// This is required for unprivileged processes. Empty maps are not written.
+///
+/// Returns the first error. Files that have been written before the error remain
+/// written.
+pub fn proc_write_id_maps(
+    proc_dir: c::c_int,
+    uid_map: &[IdMapRange],
+    gid_map: &[IdMapRange],
+    deny_setgroups: bool,
+) -> Result<()> {
+    if deny_setgroups {
+        proc_write_setgroups(proc_dir, false)?;
+    }
+    if !uid_map.is_empty() {
+        proc_write_uid_map(proc_dir, uid_map)?;
+    }
+    if !gid_map.is_empty() {
+        proc_write_gid_map(proc_dir, gid_map)?;
+    }
+    Ok(())
+}
+
+/// Opens the `/proc/<pid>` directory of the process referred to by a pidfd
+///
+/// The pid is read from `/proc/self/fdinfo`. After the directory has been opened, this
+/// function checks that the process is still alive so that the directory cannot belong
+/// to a process that reused the pid.
+///
+/// Returns `ESRCH` if the process has exited and `ENOENT` if the process is not visible
+/// in the pid namespace of `/proc`.
+pub fn pidfd_open_proc_dir(pidfd: c::c_int) -> Result<OwnedFd> {
+    let path = format!("/proc/self/fdinfo/{}", pidfd);
+    let fdinfo = open(&*path, c::O_RDONLY | c::O_CLOEXEC, 0)?;
+    let mut buf = [0u8; 4096];
+    let mut len = 0;
+    loop {
+        let n = read(*fdinfo, &mut buf[len..])?.len();
+        if n == 0 {
+            break;
+        }
+        len += n;
+        if len == buf.len() {
+            return einval();
+        }
+    }
+    let pid = buf[..len]
+        .split(|&b| b == b'\n')
+        .find_map(|line| line.strip_prefix(b"Pid:"))
+        .and_then(|pid| std::str::from_utf8(pid).ok())
+        .and_then(|pid| pid.trim().parse::<c::pid_t>().ok());
+    let pid = match pid {
+        Some(pid) if pid > 0 => pid,
+        Some(-1) => return Err(Errno(c::ESRCH)),
+        // the process is in a pid namespace that is not visible in this /proc
+        Some(0) => return Err(Errno(c::ENOENT)),
+        // not a pidfd
+        _ => return einval(),
+    };
+    let dir = open(
+        &*format!("/proc/{}", pid),
+        c::O_DIRECTORY | c::O_PATH | c::O_CLOEXEC,
+        0,
+    )?;
+    pidfd_send_signal(pidfd, 0, None, 0)?;
+    Ok(dir)
+}
@@ -74,6 +74,20 @@ pub fn waitpid(pid: c::pid_t, options: c::c_int) -> Result<(c::pid_t, c::c_int)>
     map_err!(res).map(|pid| (pid, wstatus))
 }
 
+#[man(waitid(2))]
+///
+/// If `options` contains `WNOHANG` and no child is waitable, the `si_pid` field of the
+/// returned `siginfo_t` is `0`.
+pub fn waitid(
+    idtype: c::idtype_t,
+    id: c::id_t,
+    options: c::c_int,
+) -> Result<c::siginfo_t> {
+    let mut info: c::siginfo_t = pod_zeroed();
+    let res = unsafe { c::waitid(idtype, id, &mut info, options) };
+    map_err!(res).map(|_| info)
+}
+
 #[man(chroot(2))]
 pub fn chroot<'a>(path: impl IntoUstr<'a>) -> Result<()> {
     let path = path.into_ustr();
@@ -0,0 +1,144 @@
+#![allow(non_snake_case)]
+
+use crate::*;
+use std::mem;
+
+fn ptrace_(
+    request: c::c_uint,
+    pid: c::pid_t,
+    addr: usize,
+    data: usize,
+) -> Result<c::c_long> {
+    // Use the raw syscall so that PTRACE_PEEK* store the result in `data` instead of
+    // returning it.
+    let res =
+        unsafe { c::syscall(c::SYS_ptrace, request as usize, pid as usize, addr, data) };
+    map_err!(res)
+}
+
+#[man("ptrace(2) with request = `PTRACE_TRACEME`")]
+pub fn ptrace_traceme() -> Result<()> {
+    ptrace_(c::PTRACE_TRACEME, 0, 0, 0).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_SEIZE`")]
+pub fn ptrace_seize(pid: c::pid_t, options: c::c_int) -> Result<()> {
+    ptrace_(c::PTRACE_SEIZE, pid, 0, options as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_INTERRUPT`")]
+pub fn ptrace_interrupt(pid: c::pid_t) -> Result<()> {
+    ptrace_(c::PTRACE_INTERRUPT, pid, 0, 0).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_CONT`")]
+pub fn ptrace_cont(pid: c::pid_t, sig: c::c_int) -> Result<()> {
+    ptrace_(c::PTRACE_CONT, pid, 0, sig as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_SYSCALL`")]
+pub fn ptrace_syscall(pid: c::pid_t, sig: c::c_int) -> Result<()> {
+    ptrace_(c::PTRACE_SYSCALL, pid, 0, sig as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_DETACH`")]
+pub fn ptrace_detach(pid: c::pid_t, sig: c::c_int) -> Result<()> {
+    ptrace_(c::PTRACE_DETACH, pid, 0, sig as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_SETOPTIONS`")]
+pub fn ptrace_setoptions(pid: c::pid_t, options: c::c_int) -> Result<()> {
+    ptrace_(c::PTRACE_SETOPTIONS, pid, 0, options as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_GETEVENTMSG`")]
+pub fn ptrace_geteventmsg(pid: c::pid_t) -> Result<c::c_ulong> {
+    let mut msg: c::c_ulong = 0;
+    ptrace_(c::PTRACE_GETEVENTMSG, pid, 0, &mut msg as *mut _ as usize)?;
+    Ok(msg)
+}
+
+#[man("ptrace(2) with request = `PTRACE_PEEKDATA`")]
+///
+/// `addr` is an address in the tracee.
+pub fn ptrace_peekdata(pid: c::pid_t, addr: usize) -> Result<c::c_long> {
+    let mut data: c::c_long = 0;
+    ptrace_(c::PTRACE_PEEKDATA, pid, addr, &mut data as *mut _ as usize)?;
+    Ok(data)
+}
+
+#[man("ptrace(2) with request = `PTRACE_POKEDATA`")]
+///
+/// `addr` is an address in the tracee.
+pub fn ptrace_pokedata(pid: c::pid_t, addr: usize, data: c::c_long) -> Result<()> {
+    ptrace_(c::PTRACE_POKEDATA, pid, addr, data as usize).map(drop)
+}
+
+#[man("ptrace(2) with request = `PTRACE_GETREGSET`")]
+///
+/// `nt` is the type of the register set, e.g., `NT_PRSTATUS`. Returns the part of `buf`
+/// that was written by the kernel.
+pub fn ptrace_getregset<T: Pod + ?Sized>(
+    pid: c::pid_t,
+    nt: c::c_int,
+    buf: &mut T,
+) -> Result<&mut [u8]> {
+    unsafe {
+        let buf = as_maybe_uninit_bytes_mut2(buf);
+        let mut iov = c::iovec {
+            iov_base: buf.as_mut_ptr() as *mut _,
+            iov_len: buf.len(),
+        };
+        ptrace_(
+            c::PTRACE_GETREGSET,
+            pid,
+            nt as usize,
+            &mut iov as *mut _ as usize,
+        )?;
+        Ok(buf[..iov.iov_len].slice_assume_init_mut())
+    }
+}
+
+#[man("ptrace(2) with request = `PTRACE_GET_SYSCALL_INFO`")]
+///
+/// The `op` field of the returned value determines which member of the `u` union is
+/// valid.
+pub fn ptrace_get_syscall_info(pid: c::pid_t) -> Result<c::ptrace_syscall_info> {
+    let mut info: c::ptrace_syscall_info = unsafe { mem::zeroed() };
+    ptrace_(
+        c::PTRACE_GET_SYSCALL_INFO,
+        pid,
+        mem::size_of_val(&info),
+        &mut info as *mut _ as usize,
+    )?;
+    Ok(info)
+}
+
+#[man(ptrace(2))]
+///
+/// Returns the `PTRACE_EVENT_*` of a ptrace event stop or `0` if the status does not
+/// describe a ptrace event stop. The status must satisfy `WIFSTOPPED`.
+pub fn WPTRACEEVENT(s: c::c_int) -> c::c_int {
+    (s >> 16) & 0xff
+}
+
+#[man(ptrace(2))]
+///
+/// Returns if the status describes a syscall stop. This requires
+/// `PTRACE_O_TRACESYSGOOD`.
+pub fn WIFSYSCALLSTOP(s: c::c_int) -> bool {
+    WIFSTOPPED(s) && WSTOPSIG(s) == c::SIGTRAP | 0x80
+}
+
+#[man(ptrace(2))]
+///
+/// Returns if the status describes a group stop of a tracee attached with
+/// `PTRACE_SEIZE`.
+pub fn WIFGROUPSTOP(s: c::c_int) -> bool {
+    WIFSTOPPED(s)
+        && WPTRACEEVENT(s) == c::PTRACE_EVENT_STOP
+        && matches!(
+            WSTOPSIG(s),
+            c::SIGSTOP | c::SIGTSTP | c::SIGTTIN | c::SIGTTOU
+        )
+}
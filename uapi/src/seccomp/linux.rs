@@ -0,0 +1,120 @@
+use crate::*;
+use std::ptr;
+
+#[man(seccomp(2))]
+///
+/// # Safety
+///
+/// `args` must be valid for the operation `op`. Consult the upstream documentation.
+pub unsafe fn seccomp(
+    op: c::c_uint,
+    flags: c::c_uint,
+    args: *mut c::c_void,
+) -> Result<c::c_int> {
+    let res = c::syscall(c::SYS_seccomp, op as usize, flags as usize, args as usize);
+    map_err!(res).map(|v| v as c::c_int)
+}
+
+#[man("seccomp(2) with op = `SECCOMP_SET_MODE_STRICT`")]
+pub fn seccomp_set_mode_strict() -> Result<()> {
+    unsafe { seccomp(c::SECCOMP_SET_MODE_STRICT, 0, ptr::null_mut()).map(drop) }
+}
+
+#[man("seccomp(2) with op = `SECCOMP_SET_MODE_FILTER`")]
+///
+/// Returns the value returned by the kernel. This is usually `0` but can be a thread id
+/// or a file descriptor depending on `flags`. Use `seccomp_set_mode_filter_listener` to
+/// receive the file descriptor of `SECCOMP_FILTER_FLAG_NEW_LISTENER` as an `OwnedFd`.
+///
+/// The program is checked with `bpf_verify` before it is passed to the kernel.
+pub fn seccomp_set_mode_filter(
+    flags: c::c_uint,
+    prog: &[c::sock_filter],
+) -> Result<c::c_int> {
+    bpf_verify(prog)?;
+    let mut fprog = bpf_fprog(prog)?;
+    unsafe {
+        seccomp(
+            c::SECCOMP_SET_MODE_FILTER,
+            flags,
+            &mut fprog as *mut _ as *mut _,
+        )
+    }
+}
+
+/// Shortcut for `seccomp_set_mode_filter` with `SECCOMP_FILTER_FLAG_NEW_LISTENER`
+///
+/// Returns the listener that receives `SECCOMP_RET_USER_NOTIF` notifications.
+pub fn seccomp_set_mode_filter_listener(
+    flags: c::c_uint,
+    prog: &[c::sock_filter],
+) -> Result<OwnedFd> {
+    let flags = flags | c::SECCOMP_FILTER_FLAG_NEW_LISTENER as c::c_uint;
+    seccomp_set_mode_filter(flags, prog).map(OwnedFd::new)
+}
+
+#[man("seccomp(2) with op = `SECCOMP_GET_ACTION_AVAIL`")]
+pub fn seccomp_get_action_avail(action: u32) -> Result<()> {
+    let mut action = action;
+    unsafe {
+        seccomp(
+            c::SECCOMP_GET_ACTION_AVAIL,
+            0,
+            &mut action as *mut _ as *mut _,
+        )
+        .map(drop)
+    }
+}
+
+#[man("seccomp(2) with op = `SECCOMP_GET_NOTIF_SIZES`")]
+pub fn seccomp_get_notif_sizes() -> Result<c::seccomp_notif_sizes> {
+    let mut sizes: c::seccomp_notif_sizes = pod_zeroed();
+    unsafe {
+        seccomp(
+            c::SECCOMP_GET_NOTIF_SIZES,
+            0,
+            &mut sizes as *mut _ as *mut _,
+        )?;
+    }
+    Ok(sizes)
+}
+
+#[man("seccomp_unotify(2) with request = `SECCOMP_IOCTL_NOTIF_RECV`")]
+pub fn seccomp_ioctl_notif_recv(fd: c::c_int) -> Result<c::seccomp_notif> {
+    // the kernel requires the struct to be zeroed
+    let mut notif: c::seccomp_notif = pod_zeroed();
+    let res = unsafe { c::ioctl(fd, c::SECCOMP_IOCTL_NOTIF_RECV as _, &mut notif) };
+    map_err!(res).map(|_| notif)
+}
+
+#[man("seccomp_unotify(2) with request = `SECCOMP_IOCTL_NOTIF_SEND`")]
+pub fn seccomp_ioctl_notif_send(
+    fd: c::c_int,
+    resp: &c::seccomp_notif_resp,
+) -> Result<()> {
+    let res = unsafe { c::ioctl(fd, c::SECCOMP_IOCTL_NOTIF_SEND as _, resp) };
+    map_err!(res).map(drop)
+}
+
+#[man("seccomp_unotify(2) with request = `SECCOMP_IOCTL_NOTIF_ID_VALID`")]
+pub fn seccomp_ioctl_notif_id_valid(fd: c::c_int, id: u64) -> Result<()> {
+    let res = unsafe { c::ioctl(fd, c::SECCOMP_IOCTL_NOTIF_ID_VALID as _, &id) };
+    map_err!(res).map(drop)
+}
+
+#[man("seccomp_unotify(2) with request = `SECCOMP_IOCTL_NOTIF_ADDFD`")]
+///
+/// Returns the file descriptor number in the target process.
+pub fn seccomp_ioctl_notif_addfd(
+    fd: c::c_int,
+    addfd: &c::seccomp_notif_addfd,
+) -> Result<c::c_int> {
+    let res = unsafe { c::ioctl(fd, c::SECCOMP_IOCTL_NOTIF_ADDFD as _, addfd) };
+    map_err!(res)
+}
+
+#[man("seccomp_unotify(2) with request = `SECCOMP_IOCTL_NOTIF_SET_FLAGS`")]
+pub fn seccomp_ioctl_notif_set_flags(fd: c::c_int, flags: u64) -> Result<()> {
+    let res = unsafe { c::ioctl(fd, c::SECCOMP_IOCTL_NOTIF_SET_FLAGS as _, flags) };
+    map_err!(res).map(drop)
+}
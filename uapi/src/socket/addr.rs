@@ -0,0 +1,259 @@
+use crate::*;
+use std::{
+    any::TypeId,
+    mem,
+    mem::MaybeUninit,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    slice,
+};
+
+/// An unsized socket address
+///
+/// This type can be used to pass socket addresses whose length is smaller than the size
+/// of the corresponding C type, e.g., `c::sockaddr_un` with a short path.
+#[repr(transparent)]
+pub struct SockAddrBytes([MaybeUninit<u8>]);
+
+unsafe impl Pod for SockAddrBytes {
+}
+
+unsafe impl SockAddr for SockAddrBytes {
+}
+
+impl SockAddrBytes {
+    /// Returns the first `len` bytes of `addr`
+    ///
+    /// Returns an error if `len` is larger than the size of `T`.
+    pub fn new<T: SockAddr>(addr: &T, len: usize) -> Result<&Self> {
+        if len > mem::size_of::<T>() {
+            return einval();
+        }
+        unsafe {
+            let bytes =
+                slice::from_raw_parts(addr as *const _ as *const MaybeUninit<u8>, len);
+            Ok(&*(bytes as *const [MaybeUninit<u8>] as *const Self))
+        }
+    }
+
+    /// Returns the length of the address
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns if the address is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Converts a `SocketAddrV4` to a `c::sockaddr_in`
+pub fn sockaddr_in_from_std(addr: &SocketAddrV4) -> c::sockaddr_in {
+    let mut sin: c::sockaddr_in = pod_zeroed();
+    #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+    {
+        sin.sin_len = mem::size_of::<c::sockaddr_in>() as u8;
+    }
+    sin.sin_family = c::AF_INET as _;
+    sin.sin_port = addr.port().to_be();
+    sin.sin_addr.s_addr = u32::from(*addr.ip()).to_be();
+    sin
+}
+
+/// Converts a `c::sockaddr_in` to a `SocketAddrV4`
+pub fn sockaddr_in_to_std(addr: &c::sockaddr_in) -> SocketAddrV4 {
+    SocketAddrV4::new(
+        Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)),
+        u16::from_be(addr.sin_port),
+    )
+}
+
+/// Converts a `SocketAddrV6` to a `c::sockaddr_in6`
+pub fn sockaddr_in6_from_std(addr: &SocketAddrV6) -> c::sockaddr_in6 {
+    let mut sin6: c::sockaddr_in6 = pod_zeroed();
+    #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+    {
+        sin6.sin6_len = mem::size_of::<c::sockaddr_in6>() as u8;
+    }
+    sin6.sin6_family = c::AF_INET6 as _;
+    sin6.sin6_port = addr.port().to_be();
+    sin6.sin6_flowinfo = addr.flowinfo();
+    sin6.sin6_addr.s6_addr = addr.ip().octets();
+    sin6.sin6_scope_id = addr.scope_id();
+    sin6
+}
+
+/// Converts a `c::sockaddr_in6` to a `SocketAddrV6`
+pub fn sockaddr_in6_to_std(addr: &c::sockaddr_in6) -> SocketAddrV6 {
+    SocketAddrV6::new(
+        Ipv6Addr::from(addr.sin6_addr.s6_addr),
+        u16::from_be(addr.sin6_port),
+        addr.sin6_flowinfo,
+        addr.sin6_scope_id,
+    )
+}
+
+/// Converts a `SocketAddr` to a `c::sockaddr_storage`
+///
+/// Returns the address and its length. Use `SockAddrBytes::new` to pass the address to
+/// functions such as `bind` or `connect`.
+pub fn sockaddr_storage_from_std(addr: &SocketAddr) -> (c::sockaddr_storage, usize) {
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = match addr {
+        SocketAddr::V4(addr) => write_prefix(&mut storage, &sockaddr_in_from_std(addr)),
+        SocketAddr::V6(addr) => write_prefix(&mut storage, &sockaddr_in6_from_std(addr)),
+    };
+    (storage, len)
+}
+
+fn write_prefix<T: SockAddr>(storage: &mut c::sockaddr_storage, addr: &T) -> usize {
+    let len = mem::size_of::<T>();
+    assert!(len <= mem::size_of::<c::sockaddr_storage>());
+    unsafe {
+        let dst = storage as *mut _ as *mut u8;
+        dst.copy_from_nonoverlapping(addr as *const _ as *const u8, len);
+    }
+    len
+}
+
+/// Reads a socket address from a `c::sockaddr_storage`
+///
+/// `len` is the length of the address as returned by functions such as `accept`,
+/// `recvfrom`, or `getpeername`. `T` should be chosen based on `storage.ss_family`.
+///
+/// `T` must be one of `c::sockaddr_in`, `c::sockaddr_in6`, `c::sockaddr_un`, and, on
+/// Linux, `c::sockaddr_nl`, `c::sockaddr_ll`, `c::sockaddr_vm`, or `c::sockaddr_alg`.
+///
+/// Returns an error if `ss_family` is not the address family of `T` or if `len` is not a
+/// valid length for `T`. Only `c::sockaddr_un` and `c::sockaddr_ll` can be shorter than
+/// their size. In this case, the remaining bytes are zeroed.
+pub fn sockaddr_storage_read<T: SockAddr + 'static>(
+    storage: &c::sockaddr_storage,
+    len: usize,
+) -> Result<T> {
+    let (family, min) = match sockaddr_family::<T>() {
+        Some(f) => f,
+        _ => return einval(),
+    };
+    let size = mem::size_of::<T>();
+    if storage.ss_family as c::c_int != family
+        || len < min
+        || len > size
+        || size > mem::size_of::<c::sockaddr_storage>()
+    {
+        return einval();
+    }
+    Ok(read_prefix(storage, len))
+}
+
+/// Returns the address family of `T` and the minimum length of such an address
+fn sockaddr_family<T: 'static>() -> Option<(c::c_int, usize)> {
+    let ty = TypeId::of::<T>();
+    let size = mem::size_of::<T>();
+    let fixed = |family| Some((family, size));
+    if ty == TypeId::of::<c::sockaddr_in>() {
+        return fixed(c::AF_INET);
+    }
+    if ty == TypeId::of::<c::sockaddr_in6>() {
+        return fixed(c::AF_INET6);
+    }
+    if ty == TypeId::of::<c::sockaddr_un>() {
+        return Some((c::AF_UNIX, sun_path_offset()));
+    }
+    #[cfg(target_os = "linux")]
+    {
+        if ty == TypeId::of::<c::sockaddr_nl>() {
+            return fixed(c::AF_NETLINK);
+        }
+        if ty == TypeId::of::<c::sockaddr_vm>() {
+            return fixed(c::AF_VSOCK);
+        }
+        if ty == TypeId::of::<c::sockaddr_alg>() {
+            return fixed(c::AF_ALG);
+        }
+        if ty == TypeId::of::<c::sockaddr_ll>() {
+            // the length of sll_addr depends on sll_halen
+            let addr: c::sockaddr_ll = pod_zeroed();
+            let offset = addr.sll_addr.as_ptr() as usize - &addr as *const _ as usize;
+            return Some((c::AF_PACKET, offset));
+        }
+    }
+    None
+}
+
+/// Reads `T` from the first `len` bytes of `storage`
+///
+/// The remaining bytes of `T` are zeroed. `len` and the size of `T` must not be larger
+/// than the size of `c::sockaddr_storage`.
+fn read_prefix<T: SockAddr>(storage: &c::sockaddr_storage, len: usize) -> T {
+    let mut t: T = pod_zeroed();
+    unsafe {
+        let dst = &mut t as *mut _ as *mut u8;
+        dst.copy_from_nonoverlapping(storage as *const _ as *const u8, len);
+    }
+    t
+}
+
+fn sun_path_offset() -> usize {
+    let addr: c::sockaddr_un = pod_zeroed();
+    addr.sun_path.as_ptr() as usize - &addr as *const _ as usize
+}
+
+/// Creates a `c::sockaddr_un` for a pathname socket
+///
+/// Returns the address and its length. The length includes the terminating nul byte.
+/// Use `SockAddrBytes::new` to pass the address to functions such as `bind` or `connect`.
+///
+/// Returns an error if the path contains a nul byte or does not fit into `sun_path`.
+pub fn sockaddr_un_path<'a>(path: impl IntoUstr<'a>) -> Result<(c::sockaddr_un, usize)> {
+    let path = path.into_ustr();
+    let bytes = path.as_bytes();
+    if bytes.contains(&0) {
+        return einval();
+    }
+    sockaddr_un_bytes(bytes, 1)
+}
+
+pub(crate) fn sockaddr_un_bytes(
+    bytes: &[u8],
+    nul: usize,
+) -> Result<(c::sockaddr_un, usize)> {
+    let mut addr: c::sockaddr_un = pod_zeroed();
+    if bytes.len() + nul > addr.sun_path.len() {
+        return Err(Errno(c::ENAMETOOLONG));
+    }
+    for (dst, &src) in addr.sun_path.iter_mut().zip(bytes) {
+        *dst = src as c::c_char;
+    }
+    let len = sun_path_offset() + bytes.len() + nul;
+    #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+    {
+        addr.sun_len = len as u8;
+    }
+    addr.sun_family = c::AF_UNIX as _;
+    Ok((addr, len))
+}
+
+/// Returns the name of a `c::sockaddr_un`
+///
+/// `len` is the length of the address. For pathname sockets, the returned name does not
+/// include the terminating nul byte. For abstract sockets, the returned name includes the
+/// leading nul byte. For unnamed sockets, the returned name is empty.
+///
+/// Returns an error if `len` is smaller than the offset of `sun_path` or larger than the
+/// size of `c::sockaddr_un`.
+pub fn sockaddr_un_name(addr: &c::sockaddr_un, len: usize) -> Result<&[u8]> {
+    let offset = sun_path_offset();
+    if len < offset || len > mem::size_of::<c::sockaddr_un>() {
+        return einval();
+    }
+    let path = unsafe {
+        slice::from_raw_parts(addr.sun_path.as_ptr() as *const u8, len - offset)
+    };
+    match path.first() {
+        Some(0) => Ok(path),
+        _ => match path.iter().position(|&b| b == 0) {
+            Some(pos) => Ok(&path[..pos]),
+            _ => Ok(path),
+        },
+    }
+}
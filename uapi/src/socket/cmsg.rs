@@ -121,3 +121,121 @@ pub fn cmsg_write<T: ?Sized>(
     *buf = &mut mem::take(buf)[cmsg_space..];
     Ok(cmsg_space)
 }
+
+pub(crate) fn cmsg_write_typed<T: ?Sized>(
+    buf: &mut &mut [MaybeUninit<u8>],
+    level: c::c_int,
+    ty: c::c_int,
+    data: &T,
+) -> Result<usize> {
+    let mut hdr: c::cmsghdr = pod_zeroed();
+    hdr.cmsg_level = level;
+    hdr.cmsg_type = ty;
+    cmsg_write(buf, hdr, data)
+}
+
+/// Returns an iterator over the cmsgs in a buffer
+///
+/// The iterator yields the cmsg header and the data buffer of each cmsg. If a cmsg
+/// cannot be read, the iterator yields an error and then stops.
+///
+/// See also the crate documentation.
+pub fn cmsg_iter(buf: &[u8]) -> CmsgIter<'_> {
+    CmsgIter { buf }
+}
+
+/// An iterator over the cmsgs in a buffer
+///
+/// See `cmsg_iter`.
+#[derive(Clone, Debug)]
+pub struct CmsgIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for CmsgIter<'a> {
+    type Item = Result<(c::cmsghdr, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        match cmsg_read(&mut self.buf) {
+            Ok((_, hdr, data)) => Some(Ok((hdr, data))),
+            Err(e) => {
+                self.buf = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Reads the data of a `SCM_RIGHTS` cmsg
+///
+/// Returns an error if the size of the data is not a multiple of the size of `c_int`.
+///
+/// The returned iterator takes ownership of the file descriptors. File descriptors that
+/// have not been yielded are closed when the iterator is dropped.
+#[allow(clippy::manual_is_multiple_of)] // usize::is_multiple_of requires rust 1.87
+pub fn cmsg_read_scm_rights(data: &[u8]) -> Result<ScmRights<'_>> {
+    if data.len() % mem::size_of::<c::c_int>() != 0 {
+        return einval();
+    }
+    Ok(ScmRights { buf: data })
+}
+
+/// An iterator over the file descriptors in a `SCM_RIGHTS` cmsg
+///
+/// See `cmsg_read_scm_rights`.
+#[derive(Debug)]
+pub struct ScmRights<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for ScmRights<'a> {
+    type Item = OwnedFd;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        let fd = pod_read_init(self.buf).unwrap();
+        self.buf = &self.buf[mem::size_of::<c::c_int>()..];
+        Some(OwnedFd::new(fd))
+    }
+}
+
+impl<'a> Drop for ScmRights<'a> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// Writes a `SCM_RIGHTS` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_scm_rights(
+    buf: &mut &mut [MaybeUninit<u8>],
+    fds: &[c::c_int],
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::SOL_SOCKET, c::SCM_RIGHTS, fds)
+}
+
+/// Reads the data of a `SCM_TIMESTAMP` cmsg
+pub fn cmsg_read_scm_timestamp(data: &[u8]) -> Result<c::timeval> {
+    pod_read(data)
+}
+
+/// Reads the data of a `IPV6_PKTINFO` cmsg
+pub fn cmsg_read_ipv6_pktinfo(data: &[u8]) -> Result<c::in6_pktinfo> {
+    pod_read(data)
+}
+
+/// Writes a `IPV6_PKTINFO` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_ipv6_pktinfo(
+    buf: &mut &mut [MaybeUninit<u8>],
+    pktinfo: &c::in6_pktinfo,
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::IPPROTO_IPV6, c::IPV6_PKTINFO, pktinfo)
+}
@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Creates a `c::sockaddr_un` for an abstract socket
+///
+/// `name` is the name of the socket without the leading nul byte. Returns the address and
+/// its length. Use `SockAddrBytes::new` to pass the address to functions such as `bind`
+/// or `connect`.
+///
+/// Returns an error if the name does not fit into `sun_path`.
+pub fn sockaddr_un_abstract(name: &[u8]) -> Result<(c::sockaddr_un, usize)> {
+    let (mut addr, len) = sockaddr_un_bytes(name, 1)?;
+    addr.sun_path.copy_within(..name.len(), 1);
+    addr.sun_path[0] = 0;
+    Ok((addr, len))
+}
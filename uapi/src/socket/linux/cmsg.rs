@@ -0,0 +1,75 @@
+use crate::*;
+use std::mem::MaybeUninit;
+
+/// Reads the data of a `SCM_CREDENTIALS` cmsg
+pub fn cmsg_read_scm_credentials(data: &[u8]) -> Result<c::ucred> {
+    pod_read(data)
+}
+
+/// Writes a `SCM_CREDENTIALS` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_scm_credentials(
+    buf: &mut &mut [MaybeUninit<u8>],
+    cred: &c::ucred,
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::SOL_SOCKET, c::SCM_CREDENTIALS, cred)
+}
+
+/// Reads the data of a `SCM_PIDFD` cmsg
+///
+/// The returned file descriptor is owned by the caller. This function must not be called
+/// more than once for the same cmsg.
+pub fn cmsg_read_scm_pidfd(data: &[u8]) -> Result<OwnedFd> {
+    pod_read(data).map(OwnedFd::new)
+}
+
+/// Reads the data of a `SCM_TIMESTAMPNS` cmsg
+pub fn cmsg_read_scm_timestampns(data: &[u8]) -> Result<c::timespec> {
+    pod_read(data)
+}
+
+/// Reads the data of a `IP_PKTINFO` cmsg
+pub fn cmsg_read_ip_pktinfo(data: &[u8]) -> Result<c::in_pktinfo> {
+    pod_read(data)
+}
+
+/// Writes a `IP_PKTINFO` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_ip_pktinfo(
+    buf: &mut &mut [MaybeUninit<u8>],
+    pktinfo: &c::in_pktinfo,
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::IPPROTO_IP, c::IP_PKTINFO, pktinfo)
+}
+
+/// Reads the data of a `IP_TTL` cmsg
+pub fn cmsg_read_ip_ttl(data: &[u8]) -> Result<c::c_int> {
+    pod_read(data)
+}
+
+/// Writes a `IP_TTL` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_ip_ttl(
+    buf: &mut &mut [MaybeUninit<u8>],
+    ttl: c::c_int,
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::IPPROTO_IP, c::IP_TTL, &ttl)
+}
+
+/// Reads the segment size from the data of a `UDP_GRO` cmsg
+pub fn cmsg_read_udp_gro(data: &[u8]) -> Result<c::c_int> {
+    pod_read(data)
+}
+
+/// Writes a `UDP_SEGMENT` cmsg to a buffer
+///
+/// See `cmsg_write`.
+pub fn cmsg_write_udp_segment(
+    buf: &mut &mut [MaybeUninit<u8>],
+    segment_size: u16,
+) -> Result<usize> {
+    cmsg_write_typed(buf, c::SOL_UDP, c::UDP_SEGMENT, &segment_size)
+}
@@ -0,0 +1,201 @@
+use crate::*;
+use proc::beta;
+use std::ffi::CStr;
+
+/// A generic netlink family
+///
+/// See `genl_get_family`.
+#[beta]
+#[derive(Copy, Clone, Debug)]
+pub struct GenlFamily<'a> {
+    /// The id of the family
+    pub id: u16,
+    /// The version of the family
+    pub version: u32,
+    /// The size of the family-specific header
+    pub hdrsize: u32,
+    /// The maximum attribute type of the family
+    pub maxattr: u32,
+    mcast_groups: &'a [u8],
+}
+
+impl<'a> GenlFamily<'a> {
+    /// Returns an iterator over the names and ids of the multicast groups of the family
+    pub fn mcast_groups(&self) -> GenlMcastGroups<'a> {
+        GenlMcastGroups {
+            iter: nlattr_iter(self.mcast_groups),
+        }
+    }
+}
+
+/// An iterator over the multicast groups of a generic netlink family
+///
+/// See `GenlFamily::mcast_groups`.
+#[beta]
+#[derive(Clone, Debug)]
+pub struct GenlMcastGroups<'a> {
+    iter: NlattrIter<'a>,
+}
+
+impl<'a> Iterator for GenlMcastGroups<'a> {
+    type Item = (&'a CStr, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the groups have been validated by genl_get_family
+        let (_, data) = self.iter.next()?.ok()?;
+        parse_mcast_group(data).ok()
+    }
+}
+
+/// Resolves a generic netlink family by name
+///
+/// `fd` must be a `NETLINK_GENERIC` socket. This function sends a `CTRL_CMD_GETFAMILY`
+/// request to `nlctrl` and waits for the response. Messages received on the socket that
+/// do not belong to the response are discarded.
+///
+/// `buf` is used to send the request and to receive the response. The response of
+/// families with many operations can be several KiB large. If `buf` is too small to hold
+/// the response, an error is returned.
+///
+/// Returns `ENOENT` if the family does not exist.
+#[beta]
+pub fn genl_get_family<'a, 'b, T: Pod + ?Sized>(
+    fd: c::c_int,
+    name: impl IntoUstr<'b>,
+    buf: &'a mut T,
+) -> Result<GenlFamily<'a>> {
+    const SEQ: u32 = 0x75617069;
+
+    let name = name.into_ustr();
+    let buf = unsafe { as_maybe_uninit_bytes_mut2(buf) };
+
+    let mut writer = NlmsgWriter::new(
+        &mut *buf,
+        c::nlmsghdr {
+            nlmsg_len: 0,
+            nlmsg_type: c::GENL_ID_CTRL as _,
+            nlmsg_flags: c::NLM_F_REQUEST as _,
+            nlmsg_seq: SEQ,
+            nlmsg_pid: 0,
+        },
+    )?;
+    writer.write(&c::genlmsghdr {
+        cmd: c::CTRL_CMD_GETFAMILY as _,
+        version: 1,
+        reserved: 0,
+    })?;
+    {
+        let mut attr = writer.nest(c::nlattr {
+            nla_len: 0,
+            nla_type: c::CTRL_ATTR_FAMILY_NAME as _,
+        })?;
+        attr.write(name.as_bytes_with_nul())?;
+    }
+    let msg = writer.finalize()?;
+    send(fd, msg, 0)?;
+
+    // the position of the payload of the response in buf
+    let (start, end) = 'outer: loop {
+        let reader = &*recv(fd, &mut *buf, 0)?;
+        for msg in nlmsg_iter(reader) {
+            let (header, payload) = msg?;
+            if header.nlmsg_seq != SEQ {
+                continue;
+            }
+            match header.nlmsg_type as c::c_int {
+                c::GENL_ID_CTRL => {
+                    let start = payload.as_ptr() as usize - reader.as_ptr() as usize;
+                    break 'outer (start, start + payload.len());
+                }
+                c::NLMSG_ERROR => {
+                    return Err(Errno(-nlmsg_read_error(&header, payload)?.error))
+                }
+                _ => {}
+            }
+        }
+    };
+    parse_family(unsafe { buf[start..end].slice_assume_init_ref() })
+}
+
+fn parse_family(mut payload: &[u8]) -> Result<GenlFamily<'_>> {
+    let (_, genl) = nlmsg_read::<c::genlmsghdr>(&mut payload)?;
+    if genl.cmd != c::CTRL_CMD_NEWFAMILY as u8 {
+        return einval();
+    }
+    let mut family = GenlFamily {
+        id: 0,
+        version: 0,
+        hdrsize: 0,
+        maxattr: 0,
+        mcast_groups: &[],
+    };
+    let mut have_id = false;
+    for attr in nlattr_iter(payload) {
+        let (attr, data) = attr?;
+        match (attr.nla_type & c::NLA_TYPE_MASK as u16) as c::c_int {
+            c::CTRL_ATTR_FAMILY_ID => {
+                family.id = pod_read(data)?;
+                have_id = true;
+            }
+            c::CTRL_ATTR_VERSION => family.version = pod_read(data)?,
+            c::CTRL_ATTR_HDRSIZE => family.hdrsize = pod_read(data)?,
+            c::CTRL_ATTR_MAXATTR => family.maxattr = pod_read(data)?,
+            c::CTRL_ATTR_MCAST_GROUPS => {
+                for group in nlattr_iter(data) {
+                    let (_, data) = group?;
+                    parse_mcast_group(data)?;
+                }
+                family.mcast_groups = data;
+            }
+            _ => {}
+        }
+    }
+    if !have_id {
+        return einval();
+    }
+    Ok(family)
+}
+
+fn parse_mcast_group(data: &[u8]) -> Result<(&CStr, u32)> {
+    let mut name = None;
+    let mut id = None;
+    for attr in nlattr_iter(data) {
+        let (attr, data) = attr?;
+        match attr.nla_type as c::c_int {
+            c::CTRL_ATTR_MCAST_GRP_NAME => name = CStr::from_bytes_until_nul(data).ok(),
+            c::CTRL_ATTR_MCAST_GRP_ID => id = Some(pod_read(data)?),
+            _ => {}
+        }
+    }
+    match (name, id) {
+        (Some(name), Some(id)) => Ok((name, id)),
+        _ => einval(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn test_genl_get_family() -> Result<()> {
+        let socket = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_GENERIC)?;
+        let mut buf = [MaybeUninit::<u8>::uninit(); 32 * 1024];
+        let family = genl_get_family(*socket, "nlctrl", &mut buf[..])?;
+        assert_eq!(family.id, c::GENL_ID_CTRL as u16);
+        assert!(family.version > 0);
+        assert!(family
+            .mcast_groups()
+            .any(|(name, _)| name.to_bytes() == b"notify"));
+
+        assert_eq!(
+            genl_get_family(*socket, "uapi-missing", &mut buf[..]).err(),
+            Some(Errno(c::ENOENT))
+        );
+
+        let mut small = [0u8; 64];
+        assert!(genl_get_family(*socket, "nlctrl", &mut small[..]).is_err());
+        Ok(())
+    }
+}
@@ -1,8 +1,19 @@
 use crate::*;
+use std::{convert::TryInto, mem, mem::MaybeUninit, ops::Deref, ptr};
 
+pub use addr::*;
+pub use cmsg::*;
+pub use genl::*;
 pub use netlink::*;
+pub use sockopt::*;
+pub use uevent::*;
 
+mod addr;
+mod cmsg;
+mod genl;
 mod netlink;
+mod sockopt;
+mod uevent;
 
 #[man(accept4(2))]
 pub fn accept4<T: Pod + ?Sized>(
@@ -16,3 +27,151 @@ pub fn accept4<T: Pod + ?Sized>(
     let fd = map_err!(res).map(OwnedFd::new)?;
     Ok((fd, addrlen as usize))
 }
+
+/// The maximum number of messages processed by a single call of `recvmmsg` or `sendmmsg`
+pub const MMSG_BATCH_SIZE: usize = 64;
+
+/// The per-message results of `recvmmsg` and `sendmmsg`
+///
+/// The results are stored inline. This type dereferences to a slice with one element for
+/// each message that was processed.
+pub struct MmsgResults<T> {
+    buf: [MaybeUninit<T>; MMSG_BATCH_SIZE],
+    len: usize,
+}
+
+impl<T> MmsgResults<T> {
+    fn new() -> Self {
+        Self {
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, t: T) {
+        self.buf[self.len] = MaybeUninit::new(t);
+        self.len += 1;
+    }
+}
+
+impl<T> Deref for MmsgResults<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.buf[..self.len].slice_assume_init_ref() }
+    }
+}
+
+impl<T> Drop for MmsgResults<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.buf[..self.len].slice_assume_init_mut());
+        }
+    }
+}
+
+#[man(recvmmsg(2))]
+///
+/// At most `MMSG_BATCH_SIZE` messages are received. For each received message, returns
+/// the message, the size of the address, and the control message. The flags of each
+/// message are stored in the corresponding `MsghdrMut`.
+pub fn recvmmsg<
+    'b,
+    'c,
+    D: MaybeUninitIovecMut + ?Sized,
+    C: Pod + ?Sized,
+    T: SockAddr + ?Sized,
+>(
+    sockfd: c::c_int,
+    msgs: &mut [MsghdrMut<'b, 'c, '_, D, C, T>],
+    flags: c::c_int,
+    timeout: Option<&mut c::timespec>,
+) -> Result<MmsgResults<(InitializedIovec<'b>, usize, &'c [u8])>> {
+    let len = msgs.len().min(MMSG_BATCH_SIZE);
+    let msgs = &mut msgs[..len];
+    let mut c_msgs: [c::mmsghdr; MMSG_BATCH_SIZE] = pod_zeroed();
+    for (msghdr, c_msg) in msgs.iter_mut().zip(c_msgs.iter_mut()) {
+        let iov = unsafe { ptr::read(&msghdr.iov).as_iovec_mut() };
+        let c_msghdr = &mut c_msg.msg_hdr;
+        c_msghdr.msg_iov = black_box_id(iov.as_mut_ptr() as *mut _);
+        c_msghdr.msg_iovlen = iov.len().try_into().unwrap_or(Integer::MAX_VALUE);
+        if let Some(ref mut c) = msghdr.control {
+            c_msghdr.msg_control = black_box_id(*c as *mut _ as *mut _);
+            c_msghdr.msg_controllen = mem::size_of_val(*c)
+                .try_into()
+                .unwrap_or(Integer::MAX_VALUE);
+        }
+        if let Some(ref mut name) = msghdr.name {
+            c_msghdr.msg_name = black_box_id(*name as *mut _ as *mut _);
+            c_msghdr.msg_namelen = super::to_addrlen(*name)?;
+        }
+    }
+
+    let res = unsafe {
+        c::recvmmsg(
+            sockfd,
+            c_msgs.as_mut_ptr(),
+            len as _,
+            flags as _,
+            timeout.map(|t| t as *mut _).unwrap_or(ptr::null_mut()),
+        )
+    };
+    map_err!(res)?;
+
+    let mut results = MmsgResults::new();
+    for (msghdr, c_msg) in msgs.iter_mut().zip(c_msgs.iter()).take(res as usize) {
+        let c_msghdr = &c_msg.msg_hdr;
+        #[allow(clippy::unnecessary_cast)] // socklen_t on musl
+        let controllen = c_msghdr.msg_controllen as usize;
+        let ctrl = match msghdr.control {
+            Some(ref mut c) => unsafe {
+                as_maybe_uninit_bytes_mut2(ptr::read(c))[..controllen]
+                    .slice_assume_init_mut()
+            },
+            _ => &mut [],
+        };
+        msghdr.flags = c_msghdr.msg_flags;
+        let iov = unsafe {
+            let iov = ptr::read(&msghdr.iov).as_iovec_mut();
+            InitializedIovec::new(iov, c_msg.msg_len as usize)
+        };
+        results.push((iov, c_msghdr.msg_namelen as usize, &*ctrl));
+    }
+    Ok(results)
+}
+
+#[man(sendmmsg(2))]
+///
+/// At most `MMSG_BATCH_SIZE` messages are sent. Returns the number of bytes sent for each
+/// message that was sent.
+pub fn sendmmsg<D: MaybeUninitIovec + ?Sized, C: ?Sized, A: SockAddr + ?Sized>(
+    sockfd: c::c_int,
+    msgs: &[Msghdr<'_, D, C, A>],
+    flags: c::c_int,
+) -> Result<MmsgResults<usize>> {
+    let len = msgs.len().min(MMSG_BATCH_SIZE);
+    let mut c_msgs: [c::mmsghdr; MMSG_BATCH_SIZE] = pod_zeroed();
+    for (msghdr, c_msg) in msgs.iter().zip(c_msgs.iter_mut()) {
+        let (sockaddr_ptr, sockaddr_len) = super::opt_to_sockaddr(msghdr.name)?;
+        let iov = msghdr.iov.as_iovec();
+        let c_msghdr = &mut c_msg.msg_hdr;
+        c_msghdr.msg_iov = black_box_id(iov.as_ptr() as *mut _);
+        c_msghdr.msg_iovlen = iov.len().try_into().unwrap_or(Integer::MAX_VALUE);
+        if let Some(c) = msghdr.control {
+            c_msghdr.msg_control = black_box_id(c as *const _ as *mut _);
+            c_msghdr.msg_controllen =
+                mem::size_of_val(c).try_into().unwrap_or(Integer::MAX_VALUE);
+        }
+        c_msghdr.msg_name = sockaddr_ptr as *mut _;
+        c_msghdr.msg_namelen = sockaddr_len;
+    }
+
+    let res = unsafe { c::sendmmsg(sockfd, c_msgs.as_mut_ptr(), len as _, flags as _) };
+    map_err!(res)?;
+
+    let mut results = MmsgResults::new();
+    for c_msg in &c_msgs[..res as usize] {
+        results.push(c_msg.msg_len as usize);
+    }
+    Ok(results)
+}
@@ -2,6 +2,8 @@ use crate::{socket::linux::netlink::sealed::Sealed, *};
 use proc::beta;
 use std::{
     convert::{TryFrom, TryInto},
+    ffi::CStr,
+    marker::PhantomData,
     mem,
     mem::MaybeUninit,
 };
@@ -117,6 +119,33 @@ macro_rules! nlh {
 
 nlh!(nlmsghdr, nlmsg_len);
 nlh!(nlattr, nla_len);
+nlh!(rtattr, rta_len);
+
+// Fixed-size headers without a length field
+//
+// `len` returns the size of the header and `set_len` does nothing. Reading such a header
+// with `NlmsgHeaderExt::read` therefore returns an empty payload and leaves the
+// attributes in the buffer.
+macro_rules! fixed_nlh {
+    ($($ty:ident)*) => {
+        $(
+            impl NlmsgHeader for c::$ty {
+                fn len(&self) -> Result<usize> {
+                    Ok(mem::size_of::<Self>())
+                }
+
+                fn set_len(&mut self, _len: usize) -> Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+fixed_nlh!(
+    genlmsghdr ifinfomsg ifaddrmsg rtmsg ndmsg
+    sock_diag_req inet_diag_req_v2 inet_diag_msg unix_diag_req unix_diag_msg
+);
 
 fn nlmsg_read_header<'a, H: Pod + NlmsgHeader>(
     buf: &mut &'a [u8],
@@ -256,6 +285,222 @@ impl<'a, H: NlmsgHeader> Drop for NlmsgWriter<'a, H> {
     }
 }
 
+/// Returns an iterator over the netlink messages in a buffer
+///
+/// The iterator yields the header and the payload of each message. Control messages are
+/// yielded like all other messages. Use `nlmsg_read_error` and `nlmsg_read_done` to
+/// read their payloads. If a message cannot be read, the iterator yields an error and
+/// then stops.
+///
+/// See also the crate documentation.
+#[beta]
+pub fn nlmsg_iter(buf: &[u8]) -> NlmsgIter<'_> {
+    NlmsgIter { buf }
+}
+
+/// An iterator over the netlink messages in a buffer
+///
+/// See `nlmsg_iter`.
+#[beta]
+#[derive(Clone, Debug)]
+pub struct NlmsgIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for NlmsgIter<'a> {
+    type Item = Result<(c::nlmsghdr, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        match c::nlmsghdr::read(&mut self.buf) {
+            Ok((_, header, payload)) => Some(Ok((header, payload))),
+            Err(e) => {
+                self.buf = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Reads the payload of a `NLMSG_ERROR` message
+///
+/// `header` and `payload` are a message returned by `nlmsg_iter`. If the error is `0`,
+/// the message is an acknowledgment.
+///
+/// Returns an error if the message is not a `NLMSG_ERROR` message or if the payload is
+/// malformed.
+#[beta]
+pub fn nlmsg_read_error<'a>(
+    header: &c::nlmsghdr,
+    mut payload: &'a [u8],
+) -> Result<NlmsgError<'a>> {
+    if header.nlmsg_type as c::c_int != c::NLMSG_ERROR {
+        return einval();
+    }
+    let (_, err) = nlmsg_read::<c::nlmsgerr>(&mut payload)?;
+    let mut request = &[][..];
+    if header.nlmsg_flags as c::c_int & c::NLM_F_CAPPED == 0 {
+        let len = err.msg.len()?;
+        let len = match len.checked_sub(mem::size_of::<c::nlmsghdr>()) {
+            Some(len) if len <= payload.len() => len,
+            _ => return einval(),
+        };
+        request = &payload[..len];
+        payload = &payload[nlmsg_align(len).min(payload.len())..];
+    }
+    Ok(NlmsgError {
+        error: err.error,
+        msg: err.msg,
+        payload: request,
+        ext_ack: NlmsgExtAck::new(header, payload),
+    })
+}
+
+/// Reads the payload of a `NLMSG_DONE` message
+///
+/// `header` and `payload` are a message returned by `nlmsg_iter`. Returns the error
+/// reported by the kernel (`0` on success) and the extended acknowledgment.
+///
+/// Returns an error if the message is not a `NLMSG_DONE` message or if the payload is
+/// malformed.
+#[beta]
+pub fn nlmsg_read_done<'a>(
+    header: &c::nlmsghdr,
+    mut payload: &'a [u8],
+) -> Result<(c::c_int, NlmsgExtAck<'a>)> {
+    if header.nlmsg_type as c::c_int != c::NLMSG_DONE {
+        return einval();
+    }
+    let mut error = 0;
+    if !payload.is_empty() {
+        error = nlmsg_read(&mut payload)?.1;
+    }
+    Ok((error, NlmsgExtAck::new(header, payload)))
+}
+
+/// The contents of a `NLMSG_ERROR` message
+///
+/// See `nlmsg_read_error`.
+#[beta]
+#[derive(Copy, Clone)]
+pub struct NlmsgError<'a> {
+    /// The negated error code or `0`
+    pub error: c::c_int,
+    /// The header of the message that caused the error
+    pub msg: c::nlmsghdr,
+    /// The payload of the message that caused the error
+    ///
+    /// This is empty if `NLM_F_CAPPED` is set.
+    pub payload: &'a [u8],
+    /// The extended acknowledgment
+    pub ext_ack: NlmsgExtAck<'a>,
+}
+
+/// The extended acknowledgment of a `NLMSG_ERROR` or `NLMSG_DONE` message
+///
+/// The extended acknowledgment is only available if `NETLINK_EXT_ACK` has been enabled
+/// on the socket.
+#[beta]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NlmsgExtAck<'a> {
+    attrs: &'a [u8],
+}
+
+impl<'a> NlmsgExtAck<'a> {
+    fn new(header: &c::nlmsghdr, attrs: &'a [u8]) -> Self {
+        if header.nlmsg_flags as c::c_int & c::NLM_F_ACK_TLVS != 0 {
+            Self { attrs }
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Returns an iterator over the `NLMSGERR_ATTR_*` attributes
+    pub fn attrs(&self) -> NlattrIter<'a> {
+        nlattr_iter(self.attrs)
+    }
+
+    fn attr(&self, ty: c::c_int) -> Option<&'a [u8]> {
+        self.attrs()
+            .filter_map(|a| a.ok())
+            .find(|(hdr, _)| hdr.nla_type as c::c_int == ty)
+            .map(|(_, data)| data)
+    }
+
+    /// Returns the `NLMSGERR_ATTR_MSG` attribute
+    pub fn msg(&self) -> Option<&'a CStr> {
+        self.attr(c::NLMSGERR_ATTR_MSG)
+            .and_then(|data| CStr::from_bytes_until_nul(data).ok())
+    }
+
+    /// Returns the `NLMSGERR_ATTR_OFFS` attribute
+    ///
+    /// This is the offset of the invalid attribute in the message that caused the error.
+    pub fn offset(&self) -> Option<u32> {
+        self.attr(c::NLMSGERR_ATTR_OFFS)
+            .and_then(|data| pod_read(data).ok())
+    }
+
+    /// Returns the `NLMSGERR_ATTR_COOKIE` attribute
+    pub fn cookie(&self) -> Option<&'a [u8]> {
+        self.attr(c::NLMSGERR_ATTR_COOKIE)
+    }
+}
+
+/// Returns an iterator over the `c::nlattr` attributes in a buffer
+///
+/// If an attribute cannot be read, the iterator yields an error and then stops. Nested
+/// attributes can be iterated by calling this function on the payload of an attribute.
+///
+/// See also the crate documentation.
+#[beta]
+pub fn nlattr_iter(buf: &[u8]) -> NlattrIter<'_> {
+    NlattrIter {
+        buf,
+        _pd: PhantomData,
+    }
+}
+
+/// Returns an iterator over the `c::rtattr` attributes in a buffer
+///
+/// See `nlattr_iter`.
+#[beta]
+pub fn rtattr_iter(buf: &[u8]) -> NlattrIter<'_, c::rtattr> {
+    NlattrIter {
+        buf,
+        _pd: PhantomData,
+    }
+}
+
+/// An iterator over the attributes in a buffer
+///
+/// See `nlattr_iter` and `rtattr_iter`.
+#[beta]
+#[derive(Clone, Debug)]
+pub struct NlattrIter<'a, H = c::nlattr> {
+    buf: &'a [u8],
+    _pd: PhantomData<fn() -> H>,
+}
+
+impl<'a, H: Pod + NlmsgHeader> Iterator for NlattrIter<'a, H> {
+    type Item = Result<(H, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        match nlmsg_read_header(&mut self.buf) {
+            Ok((_, header, payload)) => Some(Ok((header, payload))),
+            Err(e) => {
+                self.buf = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -405,4 +650,133 @@ mod test {
         assert!(found_loopback);
         Ok(())
     }
+
+    fn getlink(
+        buf: &mut [MaybeUninit<u8>],
+        flags: c::c_int,
+        seq: u32,
+        ifi: c::ifinfomsg,
+    ) -> Result<&mut [u8]> {
+        let mut writer = NlmsgWriter::new(
+            buf,
+            c::nlmsghdr {
+                nlmsg_len: 0,
+                nlmsg_type: c::RTM_GETLINK,
+                nlmsg_flags: (c::NLM_F_REQUEST | flags) as _,
+                nlmsg_seq: seq,
+                nlmsg_pid: 0,
+            },
+        )?;
+        writer.write(&ifi)?;
+        writer.finalize()
+    }
+
+    #[test]
+    fn test_nlmsg_iter() -> Result<()> {
+        let socket = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_ROUTE)?;
+        let mut buf = [MaybeUninit::<u8>::uninit(); 32 * 1024];
+        let mut ifi: c::ifinfomsg = pod_zeroed();
+        ifi.ifi_family = c::AF_PACKET as _;
+        let msg = getlink(&mut buf, c::NLM_F_DUMP, 1, ifi)?;
+        send(*socket, msg, 0)?;
+        let mut found_loopback = false;
+        'outer: loop {
+            let reader = &*recv(*socket, &mut buf[..], 0)?;
+            for msg in nlmsg_iter(reader) {
+                let (header, mut payload) = msg?;
+                match header.nlmsg_type as c::c_int {
+                    c::NLMSG_DONE => {
+                        assert_eq!(header.nlmsg_seq, 1);
+                        let (error, ext_ack) = nlmsg_read_done(&header, payload)?;
+                        assert_eq!(error, 0);
+                        assert!(ext_ack.msg().is_none());
+                        break 'outer;
+                    }
+                    c::NLMSG_ERROR => {
+                        let err = nlmsg_read_error(&header, payload)?;
+                        panic!("{}", Errno(-err.error));
+                    }
+                    _ => {}
+                }
+                assert_eq!(header.nlmsg_type, c::RTM_NEWLINK);
+                assert_ne!(header.nlmsg_flags & c::NLM_F_MULTI as u16, 0);
+                let (_, ifi) = nlmsg_read::<c::ifinfomsg>(&mut payload)?;
+                for attr in rtattr_iter(payload) {
+                    let (attr, data) = attr?;
+                    if attr.rta_type == c::IFLA_IFNAME && data == b"lo\0" {
+                        assert_eq!(ifi.ifi_type, c::ARPHRD_LOOPBACK);
+                        found_loopback = true;
+                    }
+                }
+            }
+        }
+        assert!(found_loopback);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nlmsg_error() -> Result<()> {
+        let socket = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_ROUTE)?;
+        setsockopt(*socket, c::SOL_NETLINK, c::NETLINK_EXT_ACK, &1i32)?;
+        setsockopt(*socket, c::SOL_NETLINK, c::NETLINK_GET_STRICT_CHK, &1i32)?;
+        let mut buf = [MaybeUninit::<u8>::uninit(); 8 * 1024];
+
+        // acknowledgment
+        let mut ifi: c::ifinfomsg = pod_zeroed();
+        ifi.ifi_index = 1;
+        let msg = getlink(&mut buf, c::NLM_F_ACK, 2, ifi)?;
+        send(*socket, msg, 0)?;
+        let mut acked = false;
+        while !acked {
+            let reader = &*recv(*socket, &mut buf[..], 0)?;
+            for msg in nlmsg_iter(reader) {
+                let (header, payload) = msg?;
+                if header.nlmsg_type as c::c_int == c::NLMSG_ERROR {
+                    let err = nlmsg_read_error(&header, payload)?;
+                    assert_eq!(header.nlmsg_seq, 2);
+                    assert_eq!(err.error, 0);
+                    assert_eq!(err.msg.nlmsg_seq, 2);
+                    acked = true;
+                } else {
+                    assert_eq!(header.nlmsg_type, c::RTM_NEWLINK);
+                }
+            }
+        }
+
+        // error with extended acknowledgment
+        ifi.ifi_change = 1;
+        let msg = getlink(&mut buf, 0, 3, ifi)?;
+        send(*socket, msg, 0)?;
+        let reader = &*recv(*socket, &mut buf[..], 0)?;
+        let mut iter = nlmsg_iter(reader);
+        let (header, payload) = iter.next().unwrap()?;
+        assert_ne!(header.nlmsg_flags as c::c_int & c::NLM_F_ACK_TLVS, 0);
+        let err = nlmsg_read_error(&header, payload)?;
+        assert_eq!(err.error, -c::EINVAL);
+        assert_eq!(err.msg.nlmsg_type, c::RTM_GETLINK);
+        assert_eq!(err.msg.nlmsg_seq, 3);
+        let (_, req) = nlmsg_read::<c::ifinfomsg>(&mut { err.payload })?;
+        assert_eq!(req.ifi_change, 1);
+        assert!(err.ext_ack.msg().is_some());
+        assert!(iter.next().is_none());
+        assert_eq!(
+            nlmsg_read_done(&header, payload).err(),
+            Some(Errno(c::EINVAL))
+        );
+
+        // capped error
+        setsockopt(*socket, c::SOL_NETLINK, c::NETLINK_CAP_ACK, &1i32)?;
+        let msg = getlink(&mut buf, 0, 4, ifi)?;
+        send(*socket, msg, 0)?;
+        let reader = &*recv(*socket, &mut buf[..], 0)?;
+        let (header, payload) = nlmsg_iter(reader).next().unwrap()?;
+        assert_ne!(header.nlmsg_flags as c::c_int & c::NLM_F_CAPPED, 0);
+        let err = nlmsg_read_error(&header, payload)?;
+        assert_eq!(err.error, -c::EINVAL);
+        assert!(err.payload.is_empty());
+        assert!(err.ext_ack.msg().is_some());
+
+        assert!(nlmsg_iter(&[1, 2, 3]).next().unwrap().is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use crate::*;
+use std::mem;
+
+sock_opt!(bi, SOL_SOCKET, SO_BINDTOIFINDEX);
+sock_opt!(bi, SOL_SOCKET, SO_BUSY_POLL);
+sock_opt!(get, SOL_SOCKET, SO_COOKIE, ty = u64);
+sock_opt!(bi, SOL_SOCKET, SO_INCOMING_CPU);
+sock_opt!(bi, SOL_SOCKET, SO_LOCK_FILTER);
+sock_opt!(bi, SOL_SOCKET, SO_MARK);
+sock_opt!(bi, SOL_SOCKET, SO_PASSCRED);
+sock_opt!(bi, SOL_SOCKET, SO_PASSPIDFD);
+sock_opt!(bi, SOL_SOCKET, SO_PASSSEC);
+sock_opt!(get, SOL_SOCKET, SO_PEERCRED, ty = c::ucred);
+sock_opt!(bi, SOL_SOCKET, SO_PEEK_OFF);
+sock_opt!(bi, SOL_SOCKET, SO_PRIORITY);
+sock_opt!(set, SOL_SOCKET, SO_RCVBUFFORCE);
+sock_opt!(bi, SOL_SOCKET, SO_RXQ_OVFL);
+sock_opt!(set, SOL_SOCKET, SO_SNDBUFFORCE);
+sock_opt!(bi, SOL_SOCKET, SO_TIMESTAMPNS);
+sock_opt!(bi, SOL_SOCKET, SO_ZEROCOPY);
+
+sock_opt!(bi, IPPROTO_IP, IP_BIND_ADDRESS_NO_PORT);
+sock_opt!(bi, IPPROTO_IP, IP_FREEBIND);
+sock_opt!(bi, IPPROTO_IP, IP_MINTTL);
+sock_opt!(get, IPPROTO_IP, IP_MTU);
+sock_opt!(bi, IPPROTO_IP, IP_MTU_DISCOVER);
+sock_opt!(bi, IPPROTO_IP, IP_MULTICAST_ALL);
+sock_opt!(bi, IPPROTO_IP, IP_RECVERR);
+sock_opt!(bi, IPPROTO_IP, IP_RECVORIGDSTADDR);
+sock_opt!(bi, IPPROTO_IP, IP_RECVTTL);
+sock_opt!(bi, IPPROTO_IP, IP_TRANSPARENT);
+sock_opt!(bi, IPPROTO_IP, IP_UNICAST_IF);
+
+sock_opt!(bi, IPPROTO_IPV6, IPV6_FREEBIND);
+sock_opt!(get, IPPROTO_IPV6, IPV6_MTU);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_MTU_DISCOVER);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_RECVERR);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_RECVORIGDSTADDR);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_TRANSPARENT);
+
+sock_opt!(bi, IPPROTO_TCP, TCP_CORK);
+sock_opt!(bi, IPPROTO_TCP, TCP_DEFER_ACCEPT);
+sock_opt!(bi, IPPROTO_TCP, TCP_FASTOPEN_CONNECT);
+sock_opt!(bi, IPPROTO_TCP, TCP_LINGER2);
+sock_opt!(bi, IPPROTO_TCP, TCP_NOTSENT_LOWAT);
+sock_opt!(bi, IPPROTO_TCP, TCP_QUICKACK);
+sock_opt!(bi, IPPROTO_TCP, TCP_SYNCNT);
+sock_opt!(bi, IPPROTO_TCP, TCP_THIN_LINEAR_TIMEOUTS);
+sock_opt!(bi, IPPROTO_TCP, TCP_USER_TIMEOUT);
+sock_opt!(bi, IPPROTO_TCP, TCP_WINDOW_CLAMP);
+
+sock_opt!(bi, IPPROTO_UDP, UDP_CORK);
+sock_opt!(bi, IPPROTO_UDP, UDP_GRO);
+sock_opt!(bi, IPPROTO_UDP, UDP_SEGMENT);
+
+#[man("setsockopt(2) with level = `SOL_SOCKET` and optname = `SO_ATTACH_FILTER`")]
+///
+/// The program is checked with `bpf_verify` before it is passed to the kernel.
+pub fn setsockopt_so_attach_filter(
+    sockfd: c::c_int,
+    prog: &[c::sock_filter],
+) -> Result<()> {
+    bpf_verify(prog)?;
+    let fprog = bpf_fprog(prog)?;
+    setsockopt(sockfd, c::SOL_SOCKET, c::SO_ATTACH_FILTER, &fprog)
+}
+
+#[man("setsockopt(2) with level = `SOL_SOCKET` and optname = `SO_DETACH_FILTER`")]
+pub fn setsockopt_so_detach_filter(sockfd: c::c_int) -> Result<()> {
+    setsockopt(sockfd, c::SOL_SOCKET, c::SO_DETACH_FILTER, &0 as &c::c_int)
+}
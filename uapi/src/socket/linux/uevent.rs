@@ -0,0 +1,137 @@
+use crate::*;
+use std::convert::TryInto;
+
+/// The multicast group of uevents sent by the kernel
+pub const UEVENT_GROUP_KERNEL: u32 = 1;
+
+/// The multicast group of uevents sent by udev
+pub const UEVENT_GROUP_UDEV: u32 = 2;
+
+const UDEV_PREFIX: &[u8] = b"libudev\0";
+const UDEV_MAGIC: u32 = 0xfeedcafe;
+
+/// Opens a `NETLINK_KOBJECT_UEVENT` socket and binds it to a multicast group
+///
+/// `groups` is usually `UEVENT_GROUP_KERNEL` or `UEVENT_GROUP_UDEV`. `flags` is or'ed
+/// into the socket type and can be used to pass `SOCK_CLOEXEC` and `SOCK_NONBLOCK`.
+pub fn uevent_socket(groups: u32, flags: c::c_int) -> Result<OwnedFd> {
+    let fd = socket(
+        c::AF_NETLINK,
+        c::SOCK_DGRAM | flags,
+        c::NETLINK_KOBJECT_UEVENT,
+    )?;
+    let mut addr: c::sockaddr_nl = pod_zeroed();
+    addr.nl_family = c::AF_NETLINK as _;
+    addr.nl_groups = groups;
+    bind(*fd, &addr)?;
+    Ok(fd)
+}
+
+/// A parsed uevent
+///
+/// See `uevent_parse`.
+#[derive(Copy, Clone, Debug)]
+pub struct Uevent<'a> {
+    /// The action, e.g., `add` or `remove`
+    pub action: &'a Bstr,
+    /// The path of the device in sysfs without the `/sys` prefix
+    pub devpath: &'a Bstr,
+    properties: &'a [u8],
+}
+
+impl<'a> Uevent<'a> {
+    /// Returns an iterator over the `KEY=VALUE` pairs of the uevent
+    pub fn properties(&self) -> UeventProperties<'a> {
+        UeventProperties {
+            buf: self.properties,
+        }
+    }
+
+    /// Returns the value of a property
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&'a Bstr> {
+        let key = key.as_ref();
+        self.properties()
+            .find(|(k, _)| k.as_bytes() == key)
+            .map(|(_, v)| v)
+    }
+}
+
+/// An iterator over the properties of a uevent
+///
+/// See `Uevent::properties`.
+#[derive(Clone, Debug)]
+pub struct UeventProperties<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for UeventProperties<'a> {
+    type Item = (&'a Bstr, &'a Bstr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.buf.is_empty() {
+                return None;
+            }
+            let (line, rest) = split_nul(self.buf);
+            self.buf = rest;
+            if let Some(pos) = line.iter().position(|&b| b == b'=') {
+                let key = Bstr::from_bytes(&line[..pos]);
+                let value = Bstr::from_bytes(&line[pos + 1..]);
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+fn split_nul(buf: &[u8]) -> (&[u8], &[u8]) {
+    match buf.iter().position(|&b| b == 0) {
+        Some(pos) => (&buf[..pos], &buf[pos + 1..]),
+        _ => (buf, &[]),
+    }
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Result<u32> {
+    match buf.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_ne_bytes(bytes.try_into().unwrap())),
+        _ => einval(),
+    }
+}
+
+/// Parses a datagram received on a `NETLINK_KOBJECT_UEVENT` socket
+///
+/// Both the kernel format (`action@devpath` followed by `KEY=VALUE` pairs) and the udev
+/// format are supported. For messages in the udev format, the action and devpath are
+/// taken from the `ACTION` and `DEVPATH` properties.
+///
+/// Returns an error if the datagram is malformed.
+pub fn uevent_parse(buf: &[u8]) -> Result<Uevent<'_>> {
+    if buf.starts_with(UDEV_PREFIX) {
+        if u32::from_be(read_u32(buf, 8)?) != UDEV_MAGIC {
+            return einval();
+        }
+        let off = read_u32(buf, 16)? as usize;
+        let len = read_u32(buf, 20)? as usize;
+        let properties = match off.checked_add(len).and_then(|end| buf.get(off..end)) {
+            Some(p) => p,
+            _ => return einval(),
+        };
+        let mut uevent = Uevent {
+            action: Bstr::empty(),
+            devpath: Bstr::empty(),
+            properties,
+        };
+        uevent.action = uevent.get("ACTION").ok_or(Errno(c::EINVAL))?;
+        uevent.devpath = uevent.get("DEVPATH").ok_or(Errno(c::EINVAL))?;
+        return Ok(uevent);
+    }
+    let (header, properties) = split_nul(buf);
+    let pos = match header.iter().position(|&b| b == b'@') {
+        Some(pos) => pos,
+        _ => return einval(),
+    };
+    Ok(Uevent {
+        action: Bstr::from_bytes(&header[..pos]),
+        devpath: Bstr::from_bytes(&header[pos + 1..]),
+        properties,
+    })
+}
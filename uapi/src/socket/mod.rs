@@ -2,9 +2,11 @@ use crate::*;
 use cfg_if::cfg_if;
 use std::{convert::TryInto, mem, ptr};
 
+pub use addr::*;
 pub use cmsg::*;
 pub use sockopt::*;
 
+mod addr;
 mod cmsg;
 mod sockopt;
 
@@ -33,3 +33,136 @@ pub fn setsockopt<T: ?Sized>(
     let res = unsafe { c::setsockopt(sockfd, level, optname, t, len) };
     map_err!(res).map(drop)
 }
+
+sock_opt!(get, SOL_SOCKET, SO_ACCEPTCONN);
+sock_opt!(bi, SOL_SOCKET, SO_BROADCAST);
+sock_opt!(bi, SOL_SOCKET, SO_DEBUG);
+sock_opt!(bi, SOL_SOCKET, SO_DONTROUTE);
+sock_opt!(get, SOL_SOCKET, SO_ERROR);
+sock_opt!(bi, SOL_SOCKET, SO_KEEPALIVE);
+sock_opt!(bi, SOL_SOCKET, SO_LINGER, ty = c::linger);
+sock_opt!(bi, SOL_SOCKET, SO_OOBINLINE);
+sock_opt!(bi, SOL_SOCKET, SO_RCVBUF);
+sock_opt!(bi, SOL_SOCKET, SO_RCVLOWAT);
+sock_opt!(bi, SOL_SOCKET, SO_RCVTIMEO, ty = c::timeval);
+sock_opt!(bi, SOL_SOCKET, SO_REUSEADDR);
+sock_opt!(bi, SOL_SOCKET, SO_REUSEPORT);
+sock_opt!(bi, SOL_SOCKET, SO_SNDBUF);
+sock_opt!(bi, SOL_SOCKET, SO_SNDLOWAT);
+sock_opt!(bi, SOL_SOCKET, SO_SNDTIMEO, ty = c::timeval);
+sock_opt!(bi, SOL_SOCKET, SO_TIMESTAMP);
+sock_opt!(get, SOL_SOCKET, SO_TYPE);
+sock_opt!(
+    #[cfg(not(target_os = "macos"))]
+    get,
+    SOL_SOCKET,
+    SO_DOMAIN
+);
+sock_opt!(
+    #[cfg(not(target_os = "macos"))]
+    get,
+    SOL_SOCKET,
+    SO_PROTOCOL
+);
+sock_opt!(
+    #[cfg(any(target_os = "macos", target_os = "freebsd"))]
+    bi,
+    SOL_SOCKET,
+    SO_NOSIGPIPE
+);
+
+sock_opt!(bi, IPPROTO_IP, IP_HDRINCL);
+sock_opt!(set, IPPROTO_IP, IP_ADD_MEMBERSHIP, ty = c::ip_mreq);
+sock_opt!(set, IPPROTO_IP, IP_DROP_MEMBERSHIP, ty = c::ip_mreq);
+sock_opt!(bi, IPPROTO_IP, IP_MULTICAST_LOOP, ty = u8);
+sock_opt!(bi, IPPROTO_IP, IP_MULTICAST_TTL, ty = u8);
+sock_opt!(bi, IPPROTO_IP, IP_TOS);
+sock_opt!(bi, IPPROTO_IP, IP_TTL);
+sock_opt!(
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    bi,
+    IPPROTO_IP,
+    IP_PKTINFO
+);
+sock_opt!(
+    #[cfg(not(target_os = "openbsd"))]
+    bi,
+    IPPROTO_IP,
+    IP_RECVTOS
+);
+
+sock_opt!(
+    #[cfg(target_os = "linux")]
+    set,
+    IPPROTO_IPV6,
+    IPV6_ADD_MEMBERSHIP,
+    ty = c::ipv6_mreq
+);
+sock_opt!(
+    #[cfg(target_os = "linux")]
+    set,
+    IPPROTO_IPV6,
+    IPV6_DROP_MEMBERSHIP,
+    ty = c::ipv6_mreq
+);
+sock_opt!(
+    #[cfg(not(target_os = "linux"))]
+    set,
+    IPPROTO_IPV6,
+    IPV6_JOIN_GROUP,
+    ty = c::ipv6_mreq
+);
+sock_opt!(
+    #[cfg(not(target_os = "linux"))]
+    set,
+    IPPROTO_IPV6,
+    IPV6_LEAVE_GROUP,
+    ty = c::ipv6_mreq
+);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_MULTICAST_HOPS);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_MULTICAST_LOOP);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_RECVPKTINFO);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_RECVTCLASS);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_TCLASS);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_UNICAST_HOPS);
+sock_opt!(bi, IPPROTO_IPV6, IPV6_V6ONLY);
+
+sock_opt!(bi, IPPROTO_TCP, TCP_MAXSEG);
+sock_opt!(bi, IPPROTO_TCP, TCP_NODELAY);
+sock_opt!(
+    #[cfg(not(target_os = "openbsd"))]
+    bi,
+    IPPROTO_TCP,
+    TCP_FASTOPEN
+);
+sock_opt!(
+    #[cfg(not(target_os = "openbsd"))]
+    bi,
+    IPPROTO_TCP,
+    TCP_KEEPCNT
+);
+sock_opt!(
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    bi,
+    IPPROTO_TCP,
+    TCP_KEEPIDLE
+);
+sock_opt!(
+    #[cfg(not(target_os = "openbsd"))]
+    bi,
+    IPPROTO_TCP,
+    TCP_KEEPINTVL
+);
+sock_opt!(
+    #[cfg(target_os = "macos")]
+    bi,
+    IPPROTO_TCP,
+    TCP_KEEPALIVE
+);
+sock_opt!(
+    #[cfg(not(target_os = "macos"))]
+    get,
+    IPPROTO_TCP,
+    TCP_INFO,
+    ty = c::tcp_info
+);
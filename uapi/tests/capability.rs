@@ -0,0 +1,63 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use uapi::*;
+
+    fn bit(cap: c::c_int) -> u64 {
+        1 << cap
+    }
+
+    #[test]
+    fn capget_version() {
+        let mut hdr = c::__user_cap_header_struct { version: 0, pid: 0 };
+        capget(&mut hdr, None).unwrap();
+        assert_eq!(hdr.version, c::_LINUX_CAPABILITY_VERSION_3);
+
+        let mut data = [pod_zeroed::<c::__user_cap_data_struct>(); 1];
+        assert_eq!(
+            capget(&mut hdr, Some(&mut data)).err(),
+            Some(Errno(c::EINVAL))
+        );
+    }
+
+    // capabilities are per-thread, so this test does not affect the other tests
+    #[test_if(root)]
+    fn sets() {
+        let sets = capget_sets(0).unwrap();
+        assert_ne!(sets.permitted & bit(c::CAP_CHOWN), 0);
+        assert_eq!(capget_sets(getpid()).unwrap(), sets);
+
+        // ambient capabilities must be permitted and inheritable
+        let caps = bit(c::CAP_NET_RAW) | bit(c::CAP_NET_BIND_SERVICE);
+        capset_sets(&CapSets {
+            inheritable: sets.inheritable | caps,
+            ..sets
+        })
+        .unwrap();
+        cap_ambient_set(caps).unwrap();
+        assert_eq!(cap_ambient_get().unwrap(), caps);
+        cap_ambient_set(0).unwrap();
+        assert_eq!(cap_ambient_get().unwrap(), 0);
+
+        let bounding = cap_bounding_get().unwrap();
+        assert_ne!(bounding & bit(c::CAP_SYS_BOOT), 0);
+        cap_bounding_drop(bit(c::CAP_SYS_BOOT)).unwrap();
+        assert_eq!(
+            cap_bounding_get().unwrap(),
+            bounding & !bit(c::CAP_SYS_BOOT)
+        );
+
+        let reduced = CapSets {
+            effective: sets.effective & !bit(c::CAP_CHOWN),
+            permitted: sets.permitted & !bit(c::CAP_CHOWN),
+            inheritable: 0,
+        };
+        capset_sets(&reduced).unwrap();
+        assert_eq!(capget_sets(0).unwrap(), reduced);
+
+        // permitted capabilities cannot be regained
+        assert_eq!(capset_sets(&sets).err(), Some(Errno(c::EPERM)));
+    }
+}
@@ -0,0 +1,47 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use std::process::exit;
+    use testutils::*;
+    use uapi::*;
+
+    #[test_if(linux_5_6)]
+    fn clone3_() {
+        let mut args: c::clone_args = pod_zeroed();
+        args.exit_signal = c::SIGCHLD as u64;
+        match unsafe { clone3(&mut args).unwrap() } {
+            0 => in_fork(|| exit(2)),
+            n => {
+                let (pid, status) = wait().unwrap();
+                assert_eq!(pid, n);
+                assert!(WIFEXITED(status));
+                assert_eq!(WEXITSTATUS(status), 2);
+            }
+        }
+
+        let mut args: c::clone_args = pod_zeroed();
+        args.exit_signal = c::SIGCHLD as u64;
+        match unsafe { clone3_pidfd(&mut args).unwrap() } {
+            (0, pidfd) => in_fork(|| {
+                assert!(pidfd.is_none());
+                exit(3)
+            }),
+            (n, pidfd) => {
+                let pidfd = pidfd.unwrap();
+                assert_eq!(args.flags, c::CLONE_PIDFD as u64);
+                assert_eq!(fcntl_getfd(*pidfd).unwrap() & c::FD_CLOEXEC, c::FD_CLOEXEC);
+                let mut pfd = [c::pollfd {
+                    fd: *pidfd,
+                    events: c::POLLIN,
+                    revents: 0,
+                }];
+                assert_eq!(poll(&mut pfd, -1).unwrap(), 1);
+                let (pid, status) = waitpid(n, 0).unwrap();
+                assert_eq!(pid, n);
+                assert_eq!(WEXITSTATUS(status), 3);
+            }
+        }
+    }
+}
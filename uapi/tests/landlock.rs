@@ -0,0 +1,47 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use testutils::*;
+    use uapi::*;
+
+    #[test_if(root, linux_5_13)]
+    fn landlock() {
+        let tmp = Tempdir::new();
+        let allowed = format!("{}/allowed", tmp);
+        mkdir(&*allowed, 0o755).unwrap();
+        create_file(format!("{}/a", allowed));
+        create_file(format!("{}/b", tmp));
+
+        let attr = c::landlock_ruleset_attr {
+            handled_access_fs: c::LANDLOCK_ACCESS_FS_READ_FILE
+                | c::LANDLOCK_ACCESS_FS_WRITE_FILE,
+            handled_access_net: 0,
+            scoped: 0,
+        };
+        let ruleset = landlock_create_ruleset(&attr, 0).unwrap();
+        assert_eq!(
+            fcntl_getfd(*ruleset).unwrap() & c::FD_CLOEXEC,
+            c::FD_CLOEXEC
+        );
+
+        let dir = open(&*allowed, c::O_PATH | c::O_CLOEXEC, 0).unwrap();
+        let rule = c::landlock_path_beneath_attr {
+            allowed_access: c::LANDLOCK_ACCESS_FS_READ_FILE,
+            parent_fd: *dir,
+        };
+        landlock_add_rule_path_beneath(*ruleset, &rule, 0).unwrap();
+        landlock_restrict_self(*ruleset, 0).unwrap();
+
+        open(format!("{}/a", allowed), c::O_RDONLY, 0).unwrap();
+        assert_eq!(
+            open(format!("{}/a", allowed), c::O_WRONLY, 0).err(),
+            Some(Errno(c::EACCES))
+        );
+        assert_eq!(
+            open(format!("{}/b", tmp), c::O_RDONLY, 0).err(),
+            Some(Errno(c::EACCES))
+        );
+    }
+}
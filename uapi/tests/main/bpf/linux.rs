@@ -0,0 +1,175 @@
+use uapi::*;
+
+fn insn(code: u32, jt: u8, jf: u8, k: u32) -> c::sock_filter {
+    c::sock_filter {
+        code: code as u16,
+        jt,
+        jf,
+        k,
+    }
+}
+
+fn fields(prog: &[c::sock_filter]) -> Vec<(u16, u8, u8, u32)> {
+    prog.iter().map(|i| (i.code, i.jt, i.jf, i.k)).collect()
+}
+
+#[test]
+fn builder() {
+    let mut b = BpfBuilder::new();
+    let accept = b.label();
+    let drop = b.label();
+    b.ld_abs(c::BPF_B, 0)
+        .jeq(b'a' as u32, Some(accept), None)
+        .jeq(b'b' as u32, None, Some(drop))
+        .ja(accept)
+        .bind(drop)
+        .ret(0)
+        .bind(accept)
+        .ret(!0);
+    let prog = b.finish().unwrap();
+    let expected = [
+        insn(c::BPF_LD | c::BPF_B | c::BPF_ABS, 0, 0, 0),
+        insn(c::BPF_JMP | c::BPF_JEQ | c::BPF_K, 3, 0, b'a' as u32),
+        insn(c::BPF_JMP | c::BPF_JEQ | c::BPF_K, 0, 1, b'b' as u32),
+        insn(c::BPF_JMP | c::BPF_JA, 0, 0, 1),
+        insn(c::BPF_RET | c::BPF_K, 0, 0, 0),
+        insn(c::BPF_RET | c::BPF_K, 0, 0, !0),
+    ];
+    assert_eq!(fields(&prog), fields(&expected));
+}
+
+#[test]
+fn builder_errors() {
+    // unbound label
+    let mut b = BpfBuilder::new();
+    let l = b.label();
+    b.ja(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+
+    // backward jump
+    let mut b = BpfBuilder::new();
+    let l = b.label();
+    b.bind(l).ja(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+
+    // label bound twice
+    let mut b = BpfBuilder::new();
+    let l = b.label();
+    b.bind(l).ret(0).bind(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+
+    // label of another builder
+    let mut other = BpfBuilder::new();
+    let l = other.label();
+    let mut b = BpfBuilder::new();
+    b.bind(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+    let mut b = BpfBuilder::new();
+    b.ja(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+
+    // conditional jump too far
+    let mut b = BpfBuilder::new();
+    let l = b.label();
+    b.jeq(0, Some(l), None);
+    for _ in 0..256 {
+        b.ret(0);
+    }
+    b.bind(l).ret(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+
+    // missing return
+    let mut b = BpfBuilder::new();
+    b.ld_imm(0);
+    assert_eq!(b.finish().err(), Some(Errno(c::EINVAL)));
+}
+
+#[test]
+fn verify() {
+    let ret = insn(c::BPF_RET | c::BPF_K, 0, 0, 0);
+    assert!(bpf_verify(&[ret]).is_ok());
+    assert_eq!(bpf_verify(&[]).err(), Some(Errno(c::EINVAL)));
+    let too_long = vec![ret; c::BPF_MAXINSNS as usize + 1];
+    assert_eq!(bpf_verify(&too_long).err(), Some(Errno(c::EINVAL)));
+
+    let jeq = insn(c::BPF_JMP | c::BPF_JEQ | c::BPF_K, 1, 0, 0);
+    assert!(bpf_verify(&[jeq, ret, ret]).is_ok());
+    assert_eq!(bpf_verify(&[jeq, ret]).err(), Some(Errno(c::EINVAL)));
+
+    let ja = insn(c::BPF_JMP | c::BPF_JA, 0, 0, 1);
+    assert!(bpf_verify(&[ja, ret, ret]).is_ok());
+    assert_eq!(bpf_verify(&[ja, ret]).err(), Some(Errno(c::EINVAL)));
+
+    let st = insn(c::BPF_ST, 0, 0, c::BPF_MEMWORDS as u32);
+    assert_eq!(bpf_verify(&[st, ret]).err(), Some(Errno(c::EINVAL)));
+
+    let div = insn(c::BPF_ALU | c::BPF_DIV | c::BPF_K, 0, 0, 0);
+    assert_eq!(bpf_verify(&[div, ret]).err(), Some(Errno(c::EINVAL)));
+
+    // load from scratch memory before a store
+    let ld = insn(c::BPF_LD | c::BPF_MEM, 0, 0, 1);
+    let ldx = insn(c::BPF_LDX | c::BPF_W | c::BPF_MEM, 0, 0, 1);
+    let st = insn(c::BPF_ST, 0, 0, 1);
+    let st2 = insn(c::BPF_ST, 0, 0, 2);
+    assert_eq!(bpf_verify(&[ld, ret]).err(), Some(Errno(c::EINVAL)));
+    assert_eq!(bpf_verify(&[st2, ldx, ret]).err(), Some(Errno(c::EINVAL)));
+    assert!(bpf_verify(&[st, ld, ldx, ret]).is_ok());
+    // the store is skipped on one path
+    let jeq = insn(c::BPF_JMP | c::BPF_JEQ | c::BPF_K, 0, 1, 0);
+    assert_eq!(
+        bpf_verify(&[jeq, st, ld, ret]).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert!(bpf_verify(&[st, jeq, st2, ld, ret]).is_ok());
+
+    // unknown opcodes
+    for &code in &[
+        c::BPF_ALU | c::BPF_NEG | c::BPF_X,
+        c::BPF_ALU | 0xb0,
+        c::BPF_JMP | 0x50,
+        c::BPF_LD | c::BPF_W | c::BPF_MSH,
+        c::BPF_LDX | c::BPF_H | c::BPF_ABS,
+        c::BPF_ST | c::BPF_MEM,
+        c::BPF_RET | c::BPF_X,
+        c::BPF_MISC | 0x40,
+        0x100 | c::BPF_RET | c::BPF_K,
+    ] {
+        let insn = insn(code, 0, 0, 0);
+        assert_eq!(bpf_verify(&[insn, ret]).err(), Some(Errno(c::EINVAL)));
+    }
+    let tax = insn(c::BPF_MISC | c::BPF_TAX, 0, 0, 0);
+    let ldx = insn(c::BPF_LDX | c::BPF_B | c::BPF_MSH, 0, 0, 14);
+    let neg = insn(c::BPF_ALU | c::BPF_NEG, 0, 0, 0);
+    assert!(bpf_verify(&[tax, ldx, neg, ret]).is_ok());
+}
+
+#[test]
+fn attach_filter() {
+    let (a, b) = socketpair(c::AF_UNIX, c::SOCK_DGRAM | c::SOCK_NONBLOCK, 0).unwrap();
+
+    let mut builder = BpfBuilder::new();
+    let drop = builder.label();
+    builder
+        .ld_abs(c::BPF_B, 0)
+        .jeq(b'a' as u32, None, Some(drop))
+        .ret(!0)
+        .bind(drop)
+        .ret(0);
+    let prog = builder.finish().unwrap();
+    setsockopt_so_attach_filter(*b, &prog).unwrap();
+
+    let mut buf = [0u8; 16];
+    send(*a, b"bad", 0).unwrap();
+    send(*a, b"abc", 0).unwrap();
+    assert_eq!(recv(*b, &mut buf[..], 0).unwrap(), b"abc");
+    assert_eq!(recv(*b, &mut buf[..], 0).err(), Some(Errno(c::EAGAIN)));
+
+    setsockopt_so_detach_filter(*b).unwrap();
+    send(*a, b"bad", 0).unwrap();
+    assert_eq!(recv(*b, &mut buf[..], 0).unwrap(), b"bad");
+
+    setsockopt_so_attach_filter(*b, &prog).unwrap();
+    setsockopt_so_lock_filter(*b, 1).unwrap();
+    assert_eq!(getsockopt_so_lock_filter(*b).unwrap(), 1);
+    assert_eq!(setsockopt_so_detach_filter(*b).err(), Some(Errno(c::EPERM)));
+}
@@ -282,3 +282,32 @@ fn openat2_() {
         assert_eq!(err.0, c::ENOENT);
     }
 }
+
+#[test]
+fn statx_() {
+    let tmp = Tempdir::new();
+    let path = format!("{}/a", tmp);
+    let fd = open(&*path, c::O_CREAT | c::O_RDWR, 0o640).unwrap();
+    write(*fd, b"hello").unwrap();
+
+    let stat = fstat(*fd).unwrap();
+    let stx = statx(c::AT_FDCWD, &*path, 0, c::STATX_BASIC_STATS).unwrap();
+    assert_eq!(stx.stx_mask & c::STATX_BASIC_STATS, c::STATX_BASIC_STATS);
+    assert_eq!(stx.stx_ino, stat.st_ino);
+    assert_eq!(stx.stx_size, 5);
+    assert_eq!(stx.stx_mode as c::mode_t, stat.st_mode);
+    assert_eq!(
+        makedev(stx.stx_dev_major as _, stx.stx_dev_minor as _),
+        stat.st_dev
+    );
+    assert_eq!(stx.stx_mtime.tv_sec, stat.st_mtime);
+
+    let stx = statx(*fd, Ustr::empty(), c::AT_EMPTY_PATH, c::STATX_MNT_ID).unwrap();
+    assert_eq!(stx.stx_mask & c::STATX_MNT_ID, c::STATX_MNT_ID);
+    assert_ne!(stx.stx_mnt_id, 0);
+
+    assert_eq!(
+        statx(c::AT_FDCWD, format!("{}/b", tmp), 0, c::STATX_BASIC_STATS).err(),
+        Some(Errno(c::ENOENT))
+    );
+}
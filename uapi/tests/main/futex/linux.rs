@@ -0,0 +1,166 @@
+use proc::*;
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering::SeqCst},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use uapi::*;
+
+fn timeout() -> c::timespec {
+    c::timespec {
+        tv_sec: 0,
+        tv_nsec: 10_000_000,
+    }
+}
+
+fn deadline(clockid: c::clockid_t) -> c::timespec {
+    let mut now = pod_zeroed();
+    clock_gettime(clockid, &mut now).unwrap();
+    now.tv_nsec += 10_000_000;
+    if now.tv_nsec >= 1_000_000_000 {
+        now.tv_sec += 1;
+        now.tv_nsec -= 1_000_000_000;
+    }
+    now
+}
+
+fn wake_until_finished<T>(t: thread::JoinHandle<T>, wake: impl Fn()) -> T {
+    while !t.is_finished() {
+        wake();
+        thread::sleep(Duration::from_millis(1));
+    }
+    t.join().unwrap()
+}
+
+#[test]
+fn wait_wake() {
+    let f = Arc::new(AtomicU32::new(0));
+
+    assert_eq!(
+        futex_wait(&f, 1, None, c::FUTEX_PRIVATE_FLAG).err(),
+        Some(Errno(c::EAGAIN))
+    );
+    assert_eq!(
+        futex_wait(&f, 0, Some(&timeout()), c::FUTEX_PRIVATE_FLAG).err(),
+        Some(Errno(c::ETIMEDOUT))
+    );
+    assert_eq!(futex_wake(&f, 1, c::FUTEX_PRIVATE_FLAG).unwrap(), 0);
+
+    let f2 = f.clone();
+    let t = thread::spawn(move || {
+        while f2.load(SeqCst) == 0 {
+            let _ = futex_wait(&f2, 0, None, c::FUTEX_PRIVATE_FLAG);
+        }
+    });
+    f.store(1, SeqCst);
+    wake_until_finished(t, || {
+        futex_wake(&f, 1, c::FUTEX_PRIVATE_FLAG).unwrap();
+    });
+}
+
+#[test]
+fn bitset() {
+    let f = Arc::new(AtomicU32::new(0));
+
+    let d = deadline(c::CLOCK_MONOTONIC);
+    assert_eq!(
+        futex_wait_bitset(&f, 0, Some(&d), !0, 0).err(),
+        Some(Errno(c::ETIMEDOUT))
+    );
+
+    let f2 = f.clone();
+    let t = thread::spawn(move || {
+        while f2.load(SeqCst) == 0 {
+            let _ = futex_wait_bitset(&f2, 0, None, 0b01, 0);
+        }
+    });
+    f.store(1, SeqCst);
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(futex_wake_bitset(&f, 1, 0b10, 0).unwrap(), 0);
+    wake_until_finished(t, || {
+        futex_wake_bitset(&f, 1, 0b01, 0).unwrap();
+    });
+}
+
+#[test]
+fn requeue() {
+    let f = Arc::new(AtomicU32::new(0));
+    let g = AtomicU32::new(0);
+
+    assert_eq!(
+        futex_cmp_requeue(&f, 1, &g, 1, 1, c::FUTEX_PRIVATE_FLAG).err(),
+        Some(Errno(c::EAGAIN))
+    );
+    assert_eq!(
+        futex_cmp_requeue(&f, 1, &g, 1, 0, c::FUTEX_PRIVATE_FLAG).unwrap(),
+        0
+    );
+    assert_eq!(
+        futex_requeue(&f, 1, &g, 1, c::FUTEX_PRIVATE_FLAG).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn pi() {
+    let f = AtomicU32::new(0);
+
+    futex_trylock_pi(&f, c::FUTEX_PRIVATE_FLAG).unwrap();
+    assert_eq!(f.load(SeqCst) & c::FUTEX_TID_MASK, gettid() as u32);
+    assert_eq!(
+        futex_trylock_pi(&f, c::FUTEX_PRIVATE_FLAG).err(),
+        Some(Errno(c::EDEADLK))
+    );
+    futex_unlock_pi(&f, c::FUTEX_PRIVATE_FLAG).unwrap();
+    assert_eq!(f.load(SeqCst), 0);
+
+    futex_lock_pi(&f, None, c::FUTEX_PRIVATE_FLAG).unwrap();
+    assert_eq!(f.load(SeqCst) & c::FUTEX_TID_MASK, gettid() as u32);
+    futex_unlock_pi(&f, c::FUTEX_PRIVATE_FLAG).unwrap();
+}
+
+#[test_if(linux_5_16)]
+fn waitv() {
+    let f = Arc::new(AtomicU32::new(0));
+    let g = Arc::new(AtomicU32::new(0));
+
+    let waiters = [
+        futex_waitv_entry(&f, 0, c::FUTEX2_PRIVATE),
+        futex_waitv_entry(&g, 1, c::FUTEX2_PRIVATE),
+    ];
+    assert_eq!(
+        futex_waitv(&waiters, 0, None, c::CLOCK_MONOTONIC).err(),
+        Some(Errno(c::EAGAIN))
+    );
+
+    let d = deadline(c::CLOCK_MONOTONIC);
+    let waiters = [
+        futex_waitv_entry(&f, 0, c::FUTEX2_PRIVATE),
+        futex_waitv_entry(&g, 0, c::FUTEX2_PRIVATE),
+    ];
+    assert_eq!(
+        futex_waitv(&waiters, 0, Some(&d), c::CLOCK_MONOTONIC).err(),
+        Some(Errno(c::ETIMEDOUT))
+    );
+
+    let (f2, g2) = (f.clone(), g.clone());
+    let t = thread::spawn(move || loop {
+        let waiters = [
+            futex_waitv_entry(&f2, 0, c::FUTEX2_PRIVATE),
+            futex_waitv_entry(&g2, 0, c::FUTEX2_PRIVATE),
+        ];
+        match futex_waitv(&waiters, 0, None, c::CLOCK_MONOTONIC) {
+            Ok(idx) => return idx,
+            Err(Errno(c::EAGAIN)) => return 1,
+            Err(_) => {}
+        }
+    });
+    g.store(1, SeqCst);
+    let idx = wake_until_finished(t, || {
+        futex_wake(&g, 1, c::FUTEX_PRIVATE_FLAG).unwrap();
+    });
+    assert_eq!(idx, 1);
+}
@@ -0,0 +1,100 @@
+use proc::*;
+use std::mem::MaybeUninit;
+use uapi::*;
+
+fn sqe(opcode: u8, user_data: u64) -> c::io_uring_sqe {
+    let mut sqe: c::io_uring_sqe = pod_zeroed();
+    sqe.opcode = opcode;
+    sqe.user_data = user_data;
+    sqe
+}
+
+#[test]
+fn setup() {
+    let mut params = pod_zeroed();
+    let fd = io_uring_setup(4, &mut params).unwrap();
+    assert_eq!(params.sq_entries, 4);
+    assert!(params.cq_entries >= 4);
+    assert_eq!(fcntl_getfd(*fd).unwrap() & c::FD_CLOEXEC, c::FD_CLOEXEC);
+
+    assert_eq!(
+        io_uring_enter(*fd, 0, 0, c::IORING_ENTER_EXT_ARG, None).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert_eq!(io_uring_enter(*fd, 0, 0, 0, None).unwrap(), 0);
+}
+
+#[test]
+fn nop() {
+    let mut params = pod_zeroed();
+    let mut ring = IoUring::new(2, &mut params).unwrap();
+    assert_eq!(ring.sq_space_left(), 2);
+
+    unsafe {
+        ring.push_sqe(&sqe(c::IORING_OP_NOP, 1)).unwrap();
+        ring.push_sqe(&sqe(c::IORING_OP_NOP, 2)).unwrap();
+        assert_eq!(
+            ring.push_sqe(&sqe(c::IORING_OP_NOP, 3)).err(),
+            Some(Errno(c::EBUSY))
+        );
+    }
+    assert_eq!(ring.sq_pending(), 2);
+    assert_eq!(ring.sq_space_left(), 0);
+
+    assert_eq!(ring.submit_and_wait(2).unwrap(), 2);
+    assert_eq!(ring.sq_pending(), 0);
+    assert_eq!(ring.cq_ready(), 2);
+
+    let cqe = ring.pop_cqe().unwrap();
+    assert_eq!(cqe.user_data, 1);
+    assert_eq!(cqe.res, 0);
+    let cqe = ring.pop_cqe().unwrap();
+    assert_eq!(cqe.user_data, 2);
+    assert!(ring.pop_cqe().is_none());
+}
+
+#[test_if(linux_5_6)]
+fn read() {
+    let memfd = memfd_create("", 0).unwrap();
+    write(*memfd, b"hello world").unwrap();
+
+    let mut params = pod_zeroed();
+    let mut ring = IoUring::new(1, &mut params).unwrap();
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 5];
+    let mut sqe = sqe(c::IORING_OP_READ, 0x1234);
+    sqe.fd = *memfd;
+    sqe.off = 6;
+    sqe.addr = buf.as_mut_ptr() as u64;
+    sqe.len = buf.len() as u32;
+    unsafe {
+        ring.push_sqe(&sqe).unwrap();
+    }
+    ring.submit_and_wait(1).unwrap();
+
+    let cqe = ring.pop_cqe().unwrap();
+    assert_eq!(cqe.user_data, 0x1234);
+    assert_eq!(cqe.res, 5);
+    assert_eq!(unsafe { buf.slice_assume_init_ref() }, b"world");
+}
+
+#[test]
+fn register_eventfd() {
+    let mut params = pod_zeroed();
+    let mut ring = IoUring::new(1, &mut params).unwrap();
+    let efd = eventfd(0, 0).unwrap();
+
+    let mut raw = *efd;
+    unsafe {
+        io_uring_register(
+            ring.fd(),
+            c::IORING_REGISTER_EVENTFD,
+            &mut raw as *mut _ as *mut _,
+            1,
+        )
+        .unwrap();
+        ring.push_sqe(&sqe(c::IORING_OP_NOP, 0)).unwrap();
+    }
+    ring.submit_and_wait(1).unwrap();
+    assert_eq!(eventfd_read(*efd).unwrap(), 1);
+}
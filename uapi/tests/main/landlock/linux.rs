@@ -0,0 +1,53 @@
+use proc::*;
+use uapi::*;
+
+#[test_if(linux_5_13)]
+fn version() {
+    assert!(landlock_create_ruleset_version().unwrap() >= 1);
+}
+
+#[test_if(linux_5_13)]
+fn path_beneath() {
+    let attr = c::landlock_ruleset_attr {
+        handled_access_fs: c::LANDLOCK_ACCESS_FS_EXECUTE,
+        handled_access_net: 0,
+        scoped: 0,
+    };
+    let ruleset = landlock_create_ruleset(&attr, 0).unwrap();
+    let dir = open("/", c::O_PATH, 0).unwrap();
+
+    let mut rule = c::landlock_path_beneath_attr {
+        allowed_access: c::LANDLOCK_ACCESS_FS_EXECUTE,
+        parent_fd: *dir,
+    };
+    landlock_add_rule_path_beneath(*ruleset, &rule, 0).unwrap();
+
+    // not handled by the ruleset
+    rule.allowed_access = c::LANDLOCK_ACCESS_FS_READ_FILE;
+    assert_eq!(
+        landlock_add_rule_path_beneath(*ruleset, &rule, 0).err(),
+        Some(Errno(c::EINVAL))
+    );
+
+    let not_a_ruleset = open("/", c::O_RDONLY, 0).unwrap();
+    assert_eq!(
+        landlock_add_rule_path_beneath(*not_a_ruleset, &rule, 0).err(),
+        Some(Errno(c::EBADFD))
+    );
+}
+
+#[test_if(linux_6_7)]
+fn net_port() {
+    let attr = c::landlock_ruleset_attr {
+        handled_access_fs: 0,
+        handled_access_net: c::LANDLOCK_ACCESS_NET_BIND_TCP,
+        scoped: 0,
+    };
+    let ruleset = landlock_create_ruleset(&attr, 0).unwrap();
+
+    let rule = c::landlock_net_port_attr {
+        allowed_access: c::LANDLOCK_ACCESS_NET_BIND_TCP,
+        port: 8080,
+    };
+    landlock_add_rule_net_port(*ruleset, &rule, 0).unwrap();
+}
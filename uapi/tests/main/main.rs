@@ -2,12 +2,17 @@
 
 extern crate proc; // https://github.com/rust-lang/rust/issues/64450
 
+mod bpf;
 mod dir;
 mod errno;
 mod fcntl;
 mod fd;
 mod file;
+mod futex;
+mod io_uring;
 mod ioctl;
+mod landlock;
+mod mman;
 mod mount;
 mod other;
 mod pod;
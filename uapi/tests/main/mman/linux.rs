@@ -0,0 +1,66 @@
+use proc::*;
+use uapi::*;
+
+fn page_size() -> usize {
+    sysconf(c::_SC_PAGESIZE).unwrap() as usize
+}
+
+#[test]
+fn mremap_() {
+    let len = page_size();
+    let mut map = Mmap::anonymous(len).unwrap();
+    map[0] = 1;
+    map.mremap(4 * len, c::MREMAP_MAYMOVE).unwrap();
+    assert_eq!(map.len(), 4 * len);
+    assert_eq!(map[0], 1);
+    map[4 * len - 1] = 2;
+
+    assert_eq!(
+        map.mremap(len, c::MREMAP_MAYMOVE | c::MREMAP_FIXED).err(),
+        Some(Errno(c::EINVAL))
+    );
+}
+
+#[test]
+fn mincore_() {
+    let len = 2 * page_size();
+    let mut map = Mmap::anonymous(len).unwrap();
+    map[0] = 1;
+
+    let mut vec = [0; 2];
+    unsafe { mincore(map.as_ptr(), len, &mut vec).unwrap() };
+    assert_eq!(vec[0] & 1, 1);
+
+    let mut vec = [0; 1];
+    assert_eq!(
+        unsafe { mincore(map.as_ptr(), len, &mut vec).err() },
+        Some(Errno(c::EINVAL))
+    );
+}
+
+#[test]
+fn mlock2_() {
+    let map = Mmap::anonymous(page_size()).unwrap();
+    unsafe { mlock2(map.as_ptr(), map.len(), c::MLOCK_ONFAULT).unwrap() };
+    map.munlock().unwrap();
+}
+
+#[test_if(linux_5_15)]
+fn process_madvise_() {
+    let pidfd = pidfd_open(getpid(), 0).unwrap();
+    let len = 2 * page_size();
+    let map = Mmap::anonymous(len).unwrap();
+    let n = process_madvise(*pidfd, &[&map[..]][..], c::MADV_COLD, 0).unwrap();
+    assert_eq!(n, len);
+
+    assert_eq!(
+        process_madvise(*pidfd, &[&map[..]][..], c::MADV_COLD, 1).err(),
+        Some(Errno(c::EINVAL))
+    );
+}
+
+#[test_if(linux_5_15)]
+fn process_mrelease_() {
+    let pidfd = pidfd_open(getpid(), 0).unwrap();
+    assert_eq!(process_mrelease(*pidfd, 0).err(), Some(Errno(c::EINVAL)));
+}
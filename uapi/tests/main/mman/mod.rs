@@ -0,0 +1,74 @@
+use std::ptr;
+use testutils::*;
+use uapi::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+    }
+}
+
+fn page_size() -> usize {
+    sysconf(c::_SC_PAGESIZE).unwrap() as usize
+}
+
+#[test]
+fn anonymous() {
+    let len = 2 * page_size();
+    let mut map = Mmap::anonymous(len).unwrap();
+    assert_eq!(map.len(), len);
+    assert!(map.iter().all(|&b| b == 0));
+    map[len - 1] = 1;
+    assert_eq!(map[len - 1], 1);
+
+    map.mlock().unwrap();
+    map.munlock().unwrap();
+
+    let (ptr, len) = map.unwrap();
+    let map = unsafe { Mmap::from_raw(ptr, len) };
+    assert_eq!(map[len - 1], 1);
+}
+
+#[test]
+fn shared_file() {
+    let tmp = Tempdir::new();
+    let path = format!("{}/a", tmp);
+    let fd = open(&*path, c::O_CREAT | c::O_RDWR, 0o600).unwrap();
+    let len = page_size();
+    ftruncate(*fd, len as _).unwrap();
+
+    let mut map = unsafe {
+        Mmap::new(len, c::PROT_READ | c::PROT_WRITE, c::MAP_SHARED, *fd, 0).unwrap()
+    };
+    map[..5].copy_from_slice(b"hello");
+    map.msync(c::MS_SYNC).unwrap();
+    drop(map);
+
+    let mut buf = [0; 5];
+    pread(*fd, &mut buf[..], 0).unwrap();
+    assert_eq!(&buf, b"hello");
+}
+
+#[test]
+fn raw() {
+    let len = page_size();
+    unsafe {
+        let ptr = mmap(
+            ptr::null_mut(),
+            len,
+            c::PROT_READ,
+            c::MAP_PRIVATE | c::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+        .unwrap();
+        mprotect(ptr, len, c::PROT_READ | c::PROT_WRITE).unwrap();
+        *(ptr as *mut u8) = 1;
+        madvise(ptr, len, c::MADV_WILLNEED).unwrap();
+        munmap(ptr, len).unwrap();
+    }
+
+    let flags = c::MAP_PRIVATE | c::MAP_ANONYMOUS;
+    let res = unsafe { mmap(ptr::null_mut(), 0, c::PROT_READ, flags, -1, 0) };
+    assert_eq!(res.err(), Some(Errno(c::EINVAL)));
+}
@@ -4,8 +4,9 @@ use std::{
     fs,
     fs::File,
     io::{BufRead, BufReader},
-    os::raw::c_int,
+    os::{raw::c_int, unix::process::CommandExt},
     path::Path,
+    process::Command,
 };
 use tempfile::{tempdir, tempdir_in};
 use testutils::Tempdir;
@@ -183,3 +184,123 @@ fn fsmount1() {
     // open /proc/cpuinfo
     openat(*mnt, "cpuinfo", c::O_RDONLY, 0).unwrap();
 }
+
+#[test_if(root, linux_5_12)]
+fn mount_setattr1() {
+    let mnt = tmpfs();
+    create_file(*mnt, "a");
+
+    let mut attr: c::mount_attr = pod_zeroed();
+    attr.attr_set = c::MOUNT_ATTR_RDONLY as u64;
+    mount_setattr(*mnt, Ustr::empty(), c::AT_EMPTY_PATH as _, &attr).unwrap();
+    assert_eq!(
+        openat(*mnt, "b", c::O_CREAT | c::O_RDONLY, 0).err(),
+        Some(Errno(c::EROFS))
+    );
+
+    attr.attr_set = 0;
+    attr.attr_clr = c::MOUNT_ATTR_RDONLY as u64;
+    mount_setattr(*mnt, Ustr::empty(), c::AT_EMPTY_PATH as _, &attr).unwrap();
+    create_file(*mnt, "b");
+
+    attr.attr_clr = 0;
+    attr.propagation = !0;
+    assert_eq!(
+        mount_setattr(*mnt, Ustr::empty(), c::AT_EMPTY_PATH as _, &attr).err(),
+        Some(Errno(c::EINVAL))
+    );
+}
+
+#[test_if(root, linux_5_12)]
+fn mount_setattr_idmap() {
+    let mut child = unsafe {
+        Command::new("sleep")
+            .arg("1000")
+            .pre_exec(|| Ok(unshare(c::CLONE_NEWUSER)?))
+            .spawn()
+            .unwrap()
+    };
+    let pid = child.id();
+    fs::write(format!("/proc/{}/uid_map", pid), "0 1000 1").unwrap();
+    fs::write(format!("/proc/{}/gid_map", pid), "0 1000 1").unwrap();
+    let userns = open(format!("/proc/{}/ns/user", pid), c::O_RDONLY, 0).unwrap();
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let mnt = tmpfs();
+    create_file(*mnt, "a");
+    let idmapped = open_tree(
+        *mnt,
+        Ustr::empty(),
+        c::OPEN_TREE_CLONE | c::AT_EMPTY_PATH as c::c_uint,
+    )
+    .unwrap();
+    let mut attr: c::mount_attr = pod_zeroed();
+    attr.attr_set = c::MOUNT_ATTR_IDMAP as u64;
+    attr.userns_fd = *userns as u64;
+    mount_setattr(*idmapped, Ustr::empty(), c::AT_EMPTY_PATH as _, &attr).unwrap();
+
+    let stat = fstatat(*mnt, "a", 0).unwrap();
+    assert_eq!(stat.st_uid, 0);
+    let stat = fstatat(*idmapped, "a", 0).unwrap();
+    assert_eq!(stat.st_uid, 1000);
+    assert_eq!(stat.st_gid, 1000);
+
+    // root is not mapped in the idmapped mount
+    assert_eq!(
+        openat(*idmapped, "b", c::O_CREAT | c::O_RDONLY, 0).err(),
+        Some(Errno(c::EOVERFLOW))
+    );
+}
+
+#[test_if(linux_6_8)]
+fn listmount_statmount() {
+    let mut req: c::mnt_id_req = pod_zeroed();
+    req.mnt_id = c::LSMT_ROOT;
+    let mut ids = [0; 1024];
+    let ids = listmount(&req, &mut ids, 0).unwrap();
+    assert!(!ids.is_empty());
+
+    let mut buf = vec![0u64; 1024];
+    let mut found_root = false;
+    for &id in &*ids {
+        req.mnt_id = id;
+        req.param = c::STATMOUNT_SB_BASIC
+            | c::STATMOUNT_MNT_BASIC
+            | c::STATMOUNT_MNT_POINT
+            | c::STATMOUNT_FS_TYPE;
+        let sm = match statmount(&req, &mut buf[..], 0) {
+            Ok(sm) => sm,
+            // the mount might have disappeared in the meantime
+            Err(Errno(c::ENOENT)) => continue,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(sm.mnt_id, id);
+        assert!(sm.fs_type().is_some());
+        assert!(sm.mnt_root().is_none());
+        if sm.mnt_point().unwrap().to_bytes() == b"/" {
+            found_root = true;
+        }
+    }
+    assert!(found_root);
+
+    req.mnt_id = ids[0];
+    req.param = c::STATMOUNT_MNT_BASIC | c::STATMOUNT_OPT_ARRAY;
+    match statmount(&req, &mut buf[..], 0) {
+        Ok(sm) => {
+            if let Some(opts) = sm.opt_array() {
+                assert_eq!(opts.count(), sm.opt_num as usize);
+            }
+        }
+        // STATMOUNT_OPT_ARRAY requires linux 6.13
+        Err(Errno(c::EINVAL)) => {}
+        Err(e) => panic!("{}", e),
+    }
+
+    req.param = c::STATMOUNT_MNT_POINT;
+    let mut small = [0u8; 8];
+    assert_eq!(
+        statmount(&req, &mut small[..], 0).err(),
+        Some(Errno(c::EOVERFLOW))
+    );
+}
@@ -1,3 +1,4 @@
+use proc::*;
 use testutils::*;
 use uapi::*;
 
@@ -41,6 +42,31 @@ fn memfd() {
     assert_eq!(buf[0], 1);
 }
 
+#[test_if(linux_5_15)]
+fn memfd_secret_() {
+    // secretmem is disabled by default before linux 6.5
+    let fd = match memfd_secret(0) {
+        Err(Errno(c::ENOSYS)) => return,
+        fd => fd.unwrap(),
+    };
+    assert_ne!(fcntl_getfd(*fd).unwrap() & c::FD_CLOEXEC, c::FD_CLOEXEC);
+
+    let fd = memfd_secret(c::O_CLOEXEC as _).unwrap();
+    assert_eq!(fcntl_getfd(*fd).unwrap() & c::FD_CLOEXEC, c::FD_CLOEXEC);
+
+    let len = sysconf(c::_SC_PAGESIZE).unwrap() as usize;
+    ftruncate(*fd, len as _).unwrap();
+    let mut map = unsafe {
+        Mmap::new(len, c::PROT_READ | c::PROT_WRITE, c::MAP_SHARED, *fd, 0).unwrap()
+    };
+    map[0] = 1;
+    assert_eq!(map[0], 1);
+
+    // secret memory cannot be read through the file descriptor
+    let mut buf = [0];
+    assert!(read(*fd, &mut buf[..]).is_err());
+}
+
 #[test]
 fn syncfs_() {
     let tmp = Tempdir::new();
@@ -0,0 +1,133 @@
+use std::{
+    mem,
+    net::{SocketAddr, SocketAddrV4, SocketAddrV6},
+};
+use testutils::*;
+use uapi::*;
+
+#[test]
+fn inet() {
+    let v4: SocketAddrV4 = "127.0.0.1:1234".parse().unwrap();
+    let sin = sockaddr_in_from_std(&v4);
+    assert_eq!(sin.sin_family, c::AF_INET as c::sa_family_t);
+    assert_eq!(sin.sin_port, 1234u16.to_be());
+    assert_eq!(sin.sin_addr.s_addr, u32::from_ne_bytes([127, 0, 0, 1]));
+    assert_eq!(sockaddr_in_to_std(&sin), v4);
+
+    let v6 = SocketAddrV6::new("fe80::1".parse().unwrap(), 4321, 7, 3);
+    let sin6 = sockaddr_in6_from_std(&v6);
+    assert_eq!(sin6.sin6_family, c::AF_INET6 as c::sa_family_t);
+    assert_eq!(sin6.sin6_port, 4321u16.to_be());
+    assert_eq!(sockaddr_in6_to_std(&sin6), v6);
+
+    for addr in [SocketAddr::V4(v4), SocketAddr::V6(v6)].iter() {
+        let (storage, len) = sockaddr_storage_from_std(addr);
+        let decoded = match storage.ss_family as c::c_int {
+            c::AF_INET => SocketAddr::V4(sockaddr_in_to_std(
+                &sockaddr_storage_read(&storage, len).unwrap(),
+            )),
+            c::AF_INET6 => SocketAddr::V6(sockaddr_in6_to_std(
+                &sockaddr_storage_read(&storage, len).unwrap(),
+            )),
+            _ => panic!("unexpected address family"),
+        };
+        assert_eq!(decoded, *addr);
+    }
+}
+
+#[test]
+fn storage_read() {
+    let server = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+    let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+    let (storage, len) = sockaddr_storage_from_std(&addr);
+    bind(*server, SockAddrBytes::new(&storage, len).unwrap()).unwrap();
+    listen(*server, 1).unwrap();
+
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*server, &mut storage).unwrap() as usize;
+    assert_eq!(storage.ss_family, c::AF_INET as c::sa_family_t);
+    let server_addr: c::sockaddr_in = sockaddr_storage_read(&storage, len).unwrap();
+    let server_addr = SocketAddr::V4(sockaddr_in_to_std(&server_addr));
+    assert_ne!(server_addr.port(), 0);
+
+    let client = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+    let (storage, len) = sockaddr_storage_from_std(&server_addr);
+    connect(*client, SockAddrBytes::new(&storage, len).unwrap()).unwrap();
+
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let (_fd, len) = accept(*server, Some(&mut storage)).unwrap();
+    assert_eq!(storage.ss_family, c::AF_INET as c::sa_family_t);
+    let peer: c::sockaddr_in = sockaddr_storage_read(&storage, len).unwrap();
+
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*client, &mut storage).unwrap() as usize;
+    let client_addr: c::sockaddr_in = sockaddr_storage_read(&storage, len).unwrap();
+    assert_eq!(sockaddr_in_to_std(&client_addr), sockaddr_in_to_std(&peer));
+
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in>(&storage, 0).err(),
+        Some(Errno(c::EINVAL))
+    );
+    // truncated address
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in>(&storage, 4).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in>(&storage, len - 1).err(),
+        Some(Errno(c::EINVAL))
+    );
+    // wrong address family
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in6>(&storage, len).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_un>(&storage, len).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in>(&storage, len + 1).err(),
+        Some(Errno(c::EINVAL))
+    );
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_storage>(
+            &storage,
+            mem::size_of::<c::sockaddr_storage>() + 1
+        )
+        .err(),
+        Some(Errno(c::EINVAL))
+    );
+}
+
+#[test]
+fn unix() {
+    let tmp = Tempdir::new();
+    let path = format!("{}/socket", tmp);
+
+    let (addr, len) = sockaddr_un_path(&*path).unwrap();
+    assert_eq!(sockaddr_un_name(&addr, len).unwrap(), path.as_bytes());
+
+    let server = socket(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    bind(*server, SockAddrBytes::new(&addr, len).unwrap()).unwrap();
+
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*server, &mut storage).unwrap() as usize;
+    assert_eq!(storage.ss_family, c::AF_UNIX as c::sa_family_t);
+    let addr: c::sockaddr_un = sockaddr_storage_read(&storage, len).unwrap();
+    assert_eq!(sockaddr_un_name(&addr, len).unwrap(), path.as_bytes());
+
+    let (a, _b) = socketpair(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*a, &mut storage).unwrap() as usize;
+    let addr: c::sockaddr_un = sockaddr_storage_read(&storage, len).unwrap();
+    assert_eq!(sockaddr_un_name(&addr, len).unwrap(), b"");
+    assert_eq!(
+        sockaddr_storage_read::<c::sockaddr_in>(&storage, len).err(),
+        Some(Errno(c::EINVAL))
+    );
+
+    assert_eq!(sockaddr_un_path("a\0b").err(), Some(Errno(c::EINVAL)));
+    let long = "a".repeat(200);
+    assert_eq!(sockaddr_un_path(&*long).err(), Some(Errno(c::ENAMETOOLONG)));
+}
@@ -46,3 +46,65 @@ fn invalid() {
         Errno(c::EINVAL)
     );
 }
+
+#[test]
+fn typed() {
+    let (a, b) = socketpair(c::AF_UNIX, c::SOCK_DGRAM, 0).unwrap();
+    let fd1 = *a;
+    let fd2 = *b;
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut pktinfo: c::in6_pktinfo = pod_zeroed();
+    pktinfo.ipi6_ifindex = 3;
+    let written = {
+        let mut buf = &mut buf[..];
+        cmsg_write_scm_rights(&mut buf, &[fd1, fd2]).unwrap()
+            + cmsg_write_ipv6_pktinfo(&mut buf, &pktinfo).unwrap()
+    };
+    let buf = unsafe { buf[..written].slice_assume_init_ref() };
+
+    let mut iter = cmsg_iter(buf);
+
+    let (hdr, data) = iter.next().unwrap().unwrap();
+    assert_eq!(
+        (hdr.cmsg_level, hdr.cmsg_type),
+        (c::SOL_SOCKET, c::SCM_RIGHTS)
+    );
+    let fds: Vec<_> = cmsg_read_scm_rights(data)
+        .unwrap()
+        .map(|fd| fd.unwrap())
+        .collect();
+    assert_eq!(fds, [fd1, fd2]);
+
+    let (hdr, data) = iter.next().unwrap().unwrap();
+    assert_eq!(
+        (hdr.cmsg_level, hdr.cmsg_type),
+        (c::IPPROTO_IPV6, c::IPV6_PKTINFO)
+    );
+    assert_eq!(cmsg_read_ipv6_pktinfo(data).unwrap().ipi6_ifindex, 3);
+
+    assert!(iter.next().is_none());
+
+    assert_eq!(cmsg_read_scm_rights(&[0; 3]).err(), Some(Errno(c::EINVAL)));
+    assert_eq!(
+        cmsg_read_scm_timestamp(&[0; 3]).err(),
+        Some(Errno(c::EINVAL))
+    );
+
+    let mut iter = cmsg_iter(&[1, 2, 3]);
+    assert_eq!(iter.next().unwrap().err(), Some(Errno(c::EINVAL)));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn scm_rights_drop() {
+    let (a, b) = socketpair(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    let fds = [a.unwrap(), b.unwrap()];
+    let data = as_bytes(&fds[..]);
+    let mut iter = cmsg_read_scm_rights(data).unwrap();
+    let first = iter.next().unwrap();
+    drop(iter);
+    assert_eq!(fcntl_getfd(fds[1]).err(), Some(Errno(c::EBADF)));
+    drop(first);
+    assert_eq!(fcntl_getfd(fds[0]).err(), Some(Errno(c::EBADF)));
+}
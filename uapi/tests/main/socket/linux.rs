@@ -1,3 +1,9 @@
+use proc::*;
+use std::{
+    io::{IoSlice, IoSliceMut},
+    mem,
+    mem::MaybeUninit,
+};
 use testutils::*;
 use uapi::*;
 
@@ -30,3 +36,440 @@ fn accept4_() {
 
     thread.join().unwrap();
 }
+
+#[test]
+fn sockopt_peercred() {
+    let (a, _b) = socketpair(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    let cred = getsockopt_so_peercred(*a).unwrap();
+    assert_eq!(cred.pid, getpid());
+    assert_eq!(cred.uid, getuid());
+    assert_eq!(cred.gid, getgid());
+}
+
+#[test]
+fn sockopt_tcp_info() {
+    let server = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+    let mut addr: c::sockaddr_in = pod_zeroed();
+    addr.sin_family = c::AF_INET as _;
+    addr.sin_addr.s_addr = u32::from_ne_bytes([127, 0, 0, 1]);
+    bind(*server, &addr).unwrap();
+    listen(*server, 1).unwrap();
+    getsockname(*server, &mut addr).unwrap();
+
+    let client = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+    assert_eq!(
+        getsockopt_tcp_info(*client).unwrap().tcpi_state,
+        c::TCP_CLOSE
+    );
+    connect(*client, &addr).unwrap();
+    let info = getsockopt_tcp_info(*client).unwrap();
+    assert_eq!(info.tcpi_state, c::TCP_ESTABLISHED);
+
+    assert_ne!(getsockopt_so_cookie(*client).unwrap(), 0);
+    assert_eq!(getsockopt_so_domain(*client).unwrap(), c::AF_INET);
+    assert_eq!(getsockopt_so_protocol(*client).unwrap(), c::IPPROTO_TCP);
+}
+
+#[test]
+fn mmsg() {
+    let a = socket(c::AF_INET, c::SOCK_DGRAM, 0).unwrap();
+    let b = socket(c::AF_INET, c::SOCK_DGRAM, 0).unwrap();
+    let mut addr: c::sockaddr_in = pod_zeroed();
+    addr.sin_family = c::AF_INET as _;
+    addr.sin_addr.s_addr = u32::from_ne_bytes([127, 0, 0, 1]);
+    bind(*b, &addr).unwrap();
+    getsockname(*b, &mut addr).unwrap();
+
+    let msgs = [
+        Msghdr {
+            iov: &[IoSlice::new(b"hello"), IoSlice::new(b" world")][..],
+            control: msghdr_control_none_ref(),
+            name: Some(&addr),
+        },
+        Msghdr {
+            iov: &[IoSlice::new(b"ab")][..],
+            control: msghdr_control_none_ref(),
+            name: Some(&addr),
+        },
+        Msghdr {
+            iov: &[IoSlice::new(b"truncated")][..],
+            control: msghdr_control_none_ref(),
+            name: Some(&addr),
+        },
+    ];
+    assert_eq!(&*sendmmsg(*a, &msgs, 0).unwrap(), &[11, 2, 9]);
+    assert_eq!(sendmmsg(*a, &msgs[..0], 0).unwrap().len(), 0);
+
+    let mut bufs = [[0u8; 16]; 4];
+    let mut names: [c::sockaddr_in; 4] = pod_zeroed();
+    let mut iovs: Vec<_> = bufs
+        .iter_mut()
+        .enumerate()
+        .map(|(i, b)| [IoSliceMut::new(if i == 2 { &mut b[..4] } else { b })])
+        .collect();
+    let mut hdrs: Vec<_> = iovs
+        .iter_mut()
+        .zip(names.iter_mut())
+        .map(|(iov, name)| MsghdrMut {
+            iov: &mut iov[..],
+            control: msghdr_control_none_mut(),
+            name: Some(name),
+            flags: 0,
+        })
+        .collect();
+    let mut timeout = c::timespec {
+        tv_sec: 10,
+        tv_nsec: 0,
+    };
+    let res = recvmmsg(*b, &mut hdrs, c::MSG_DONTWAIT, Some(&mut timeout)).unwrap();
+    assert_eq!(res.len(), 3);
+
+    let data: Vec<Vec<u8>> = res
+        .iter()
+        .map(|(iov, _, _)| iov.iter().flatten().copied().collect())
+        .collect();
+    assert_eq!(data, [&b"hello world"[..], b"ab", b"trun"]);
+
+    let mut sender: c::sockaddr_in = pod_zeroed();
+    getsockname(*a, &mut sender).unwrap();
+    for (_, namelen, ctrl) in res.iter() {
+        assert_eq!(*namelen, mem::size_of::<c::sockaddr_in>());
+        assert!(ctrl.is_empty());
+    }
+    drop(res);
+
+    assert_eq!(hdrs[0].flags, 0);
+    assert_eq!(hdrs[2].flags & c::MSG_TRUNC, c::MSG_TRUNC);
+    drop(hdrs);
+    assert_eq!(names[0].sin_port, sender.sin_port);
+
+    let mut hdrs = [MsghdrMut {
+        iov: &mut iovs[3][..],
+        control: msghdr_control_none_mut(),
+        name: sockaddr_none_mut(),
+        flags: 0,
+    }];
+    assert_eq!(
+        recvmmsg(*b, &mut hdrs, c::MSG_DONTWAIT, None).err(),
+        Some(Errno(c::EAGAIN))
+    );
+}
+
+#[test]
+fn cmsg_udp() {
+    let a = socket(c::AF_INET, c::SOCK_DGRAM, 0).unwrap();
+    let b = socket(c::AF_INET, c::SOCK_DGRAM, 0).unwrap();
+    let mut addr: c::sockaddr_in = pod_zeroed();
+    addr.sin_family = c::AF_INET as _;
+    addr.sin_addr.s_addr = u32::from_ne_bytes([127, 0, 0, 1]);
+    bind(*b, &addr).unwrap();
+    getsockname(*b, &mut addr).unwrap();
+
+    setsockopt_ip_pktinfo(*b, 1).unwrap();
+    setsockopt_ip_recvttl(*b, 1).unwrap();
+    setsockopt_so_timestampns(*b, 1).unwrap();
+    setsockopt_udp_gro(*b, 1).unwrap();
+
+    let mut ctrl = [MaybeUninit::uninit(); 128];
+    let len = {
+        let mut ctrl = &mut ctrl[..];
+        cmsg_write_ip_ttl(&mut ctrl, 17).unwrap()
+            + cmsg_write_udp_segment(&mut ctrl, 4).unwrap()
+    };
+    let msghdr = Msghdr {
+        iov: &[IoSlice::new(b"aaaabbbbcc")][..],
+        control: Some(&ctrl[..len]),
+        name: Some(&addr),
+    };
+    sendmsg(*a, &msghdr, 0).unwrap();
+
+    let mut data = [0u8; 16];
+    let mut ctrl = [0u8; 256];
+    let mut msghdr = MsghdrMut {
+        iov: &mut [IoSliceMut::new(&mut data)][..],
+        control: Some(&mut ctrl[..]),
+        name: sockaddr_none_mut(),
+        flags: 0,
+    };
+    let (_, _, ctrl) = recvmsg(*b, &mut msghdr, 0).unwrap();
+
+    let mut seen = 0;
+    for cmsg in cmsg_iter(ctrl) {
+        let (hdr, data) = cmsg.unwrap();
+        match (hdr.cmsg_level, hdr.cmsg_type) {
+            (c::IPPROTO_IP, c::IP_PKTINFO) => {
+                let pktinfo = cmsg_read_ip_pktinfo(data).unwrap();
+                assert_eq!(pktinfo.ipi_addr.s_addr, addr.sin_addr.s_addr);
+                seen |= 1;
+            }
+            (c::IPPROTO_IP, c::IP_TTL) => {
+                assert_eq!(cmsg_read_ip_ttl(data).unwrap(), 17);
+                seen |= 2;
+            }
+            (c::SOL_SOCKET, c::SCM_TIMESTAMPNS) => {
+                let ts = cmsg_read_scm_timestampns(data).unwrap();
+                assert!(ts.tv_sec > 0);
+                seen |= 4;
+            }
+            (c::SOL_UDP, c::UDP_GRO) => {
+                assert_eq!(cmsg_read_udp_gro(data).unwrap(), 4);
+                seen |= 8;
+            }
+            _ => panic!("unexpected cmsg"),
+        }
+    }
+    assert_eq!(seen, 15);
+}
+
+#[test]
+fn cmsg_credentials() {
+    let (a, b) = socketpair(c::AF_UNIX, c::SOCK_DGRAM, 0).unwrap();
+    setsockopt_so_passcred(*b, 1).unwrap();
+
+    let cred = c::ucred {
+        pid: getpid(),
+        uid: geteuid(),
+        gid: getegid(),
+    };
+    let mut ctrl = [MaybeUninit::uninit(); 128];
+    let len = cmsg_write_scm_credentials(&mut &mut ctrl[..], &cred).unwrap();
+    let msghdr = Msghdr {
+        iov: &[IoSlice::new(b"x")][..],
+        control: Some(&ctrl[..len]),
+        name: sockaddr_none_ref(),
+    };
+    sendmsg(*a, &msghdr, 0).unwrap();
+
+    let mut data = [0u8; 1];
+    let mut buf = [0u8; 256];
+    let mut msghdr = MsghdrMut {
+        iov: &mut [IoSliceMut::new(&mut data)][..],
+        control: Some(&mut buf[..]),
+        name: sockaddr_none_mut(),
+        flags: 0,
+    };
+    let (_, _, ctrl) = recvmsg(*b, &mut msghdr, 0).unwrap();
+    let mut iter = cmsg_iter(ctrl);
+    let (hdr, data) = iter.next().unwrap().unwrap();
+    assert_eq!(
+        (hdr.cmsg_level, hdr.cmsg_type),
+        (c::SOL_SOCKET, c::SCM_CREDENTIALS)
+    );
+    let res = cmsg_read_scm_credentials(data).unwrap();
+    assert_eq!((res.pid, res.uid, res.gid), (cred.pid, cred.uid, cred.gid));
+    assert!(iter.next().is_none());
+}
+
+#[test_if(linux_6_7)]
+fn cmsg_pidfd() {
+    let (a, b) = socketpair(c::AF_UNIX, c::SOCK_DGRAM, 0).unwrap();
+    setsockopt_so_passpidfd(*b, 1).unwrap();
+    send(*a, b"x", 0).unwrap();
+
+    let mut data = [0u8; 1];
+    let mut buf = [0u8; 256];
+    let mut msghdr = MsghdrMut {
+        iov: &mut [IoSliceMut::new(&mut data)][..],
+        control: Some(&mut buf[..]),
+        name: sockaddr_none_mut(),
+        flags: 0,
+    };
+    let (_, _, ctrl) = recvmsg(*b, &mut msghdr, 0).unwrap();
+    let (hdr, data) = cmsg_iter(ctrl).next().unwrap().unwrap();
+    assert_eq!(
+        (hdr.cmsg_level, hdr.cmsg_type),
+        (c::SOL_SOCKET, c::SCM_PIDFD)
+    );
+    let pidfd = cmsg_read_scm_pidfd(data).unwrap();
+    pidfd_send_signal(*pidfd, 0, None, 0).unwrap();
+}
+
+#[test]
+fn sockaddr_un_abstract_() {
+    let name = format!("uapi-test-{}", getpid());
+    let (addr, len) = sockaddr_un_abstract(name.as_bytes()).unwrap();
+    assert_eq!(len, 2 + 1 + name.len());
+    let server = socket(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    bind(*server, SockAddrBytes::new(&addr, len).unwrap()).unwrap();
+
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*server, &mut storage).unwrap() as usize;
+    assert_eq!(storage.ss_family, c::AF_UNIX as c::sa_family_t);
+    let addr: c::sockaddr_un = sockaddr_storage_read(&storage, len).unwrap();
+    let res = sockaddr_un_name(&addr, len).unwrap();
+    assert_eq!(res[0], 0);
+    assert_eq!(&res[1..], name.as_bytes());
+
+    let nl = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_ROUTE).unwrap();
+    let mut addr: c::sockaddr_nl = pod_zeroed();
+    addr.nl_family = c::AF_NETLINK as _;
+    bind(*nl, &addr).unwrap();
+    let mut storage: c::sockaddr_storage = pod_zeroed();
+    let len = getsockname(*nl, &mut storage).unwrap() as usize;
+    assert_eq!(storage.ss_family, c::AF_NETLINK as c::sa_family_t);
+    let addr: c::sockaddr_nl = sockaddr_storage_read(&storage, len).unwrap();
+    assert_ne!(addr.nl_pid, 0);
+}
+
+#[test]
+fn uevent_parse_() {
+    let kernel = b"add@/devices/virtual/mem/null\0ACTION=add\0DEVPATH=/devices/virtual/mem/null\0SUBSYSTEM=mem\0SEQNUM=7\0";
+    let uevent = uevent_parse(kernel).unwrap();
+    assert_eq!(uevent.action.as_bytes(), b"add");
+    assert_eq!(uevent.devpath.as_bytes(), b"/devices/virtual/mem/null");
+    assert_eq!(uevent.get("SUBSYSTEM").unwrap().as_bytes(), b"mem");
+    assert!(uevent.get("MAJOR").is_none());
+    let keys: Vec<_> = uevent.properties().map(|(k, _)| k.as_bytes()).collect();
+    assert_eq!(
+        keys,
+        [
+            &b"ACTION"[..],
+            &b"DEVPATH"[..],
+            &b"SUBSYSTEM"[..],
+            &b"SEQNUM"[..]
+        ]
+    );
+
+    let properties = b"ACTION=remove\0DEVPATH=/devices/foo\0";
+    let mut udev = b"libudev\0".to_vec();
+    udev.extend_from_slice(&0xfeedcafeu32.to_be_bytes());
+    udev.extend_from_slice(&40u32.to_ne_bytes());
+    udev.extend_from_slice(&40u32.to_ne_bytes());
+    udev.extend_from_slice(&(properties.len() as u32).to_ne_bytes());
+    udev.resize(40, 0);
+    udev.extend_from_slice(properties);
+    let uevent = uevent_parse(&udev).unwrap();
+    assert_eq!(uevent.action.as_bytes(), b"remove");
+    assert_eq!(uevent.devpath.as_bytes(), b"/devices/foo");
+
+    udev[8] = 0;
+    assert_eq!(uevent_parse(&udev).err().unwrap(), Errno(c::EINVAL));
+    assert_eq!(uevent_parse(b"garbage\0").err().unwrap(), Errno(c::EINVAL));
+}
+
+#[test_if(root)]
+fn uevent_socket_() {
+    let fd = match uevent_socket(UEVENT_GROUP_KERNEL, c::SOCK_CLOEXEC) {
+        Ok(fd) => fd,
+        // uevents are not available in non-initial network namespaces
+        Err(Errno(c::EPERM)) | Err(Errno(c::EPROTONOSUPPORT)) => return,
+        Err(e) => panic!("{}", e),
+    };
+    let tv = c::timeval {
+        tv_sec: 10,
+        tv_usec: 0,
+    };
+    setsockopt(*fd, c::SOL_SOCKET, c::SO_RCVTIMEO, &tv).unwrap();
+    std::fs::write("/sys/class/mem/null/uevent", "change").unwrap();
+    let mut buf = [0u8; 8192];
+    loop {
+        let msg = recv(*fd, &mut buf[..], 0).unwrap();
+        let uevent = uevent_parse(msg).unwrap();
+        if uevent.devpath.as_bytes().ends_with(b"/mem/null") {
+            assert_eq!(uevent.action.as_bytes(), b"change");
+            assert_eq!(uevent.get("SUBSYSTEM").unwrap().as_bytes(), b"mem");
+            break;
+        }
+    }
+}
+
+fn sock_diag_header() -> c::nlmsghdr {
+    c::nlmsghdr {
+        nlmsg_len: 0,
+        nlmsg_type: c::SOCK_DIAG_BY_FAMILY,
+        nlmsg_flags: (c::NLM_F_REQUEST | c::NLM_F_DUMP) as _,
+        nlmsg_seq: 0,
+        nlmsg_pid: 0,
+    }
+}
+
+#[test]
+fn sock_diag_inet() {
+    let server = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+    let addr = sockaddr_in_from_std(&"127.0.0.1:0".parse().unwrap());
+    bind(*server, &addr).unwrap();
+    listen(*server, 1).unwrap();
+    let mut addr: c::sockaddr_in = pod_zeroed();
+    getsockname(*server, &mut addr).unwrap();
+    let ino = fstat(*server).unwrap().st_ino;
+
+    let fd = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_SOCK_DIAG).unwrap();
+    let mut buf = [MaybeUninit::<u8>::uninit(); 1024];
+    let mut writer = NlmsgWriter::new(&mut buf[..], sock_diag_header()).unwrap();
+    let mut req: c::inet_diag_req_v2 = pod_zeroed();
+    req.sdiag_family = c::AF_INET as _;
+    req.sdiag_protocol = c::IPPROTO_TCP as _;
+    req.idiag_ext = 1 << (c::INET_DIAG_INFO - 1);
+    req.idiag_states = 1 << c::TCP_LISTEN;
+    writer.write(&req).unwrap();
+
+    let mut found = false;
+    nlmsg_dump(*fd, writer.finalize().unwrap(), |mut payload| {
+        let (_, msg, _) = c::inet_diag_msg::read(&mut payload).unwrap();
+        if msg.idiag_inode as c::ino_t != ino {
+            return;
+        }
+        assert_eq!(msg.idiag_state, c::TCP_LISTEN);
+        assert_eq!(msg.id.idiag_sport, addr.sin_port);
+        assert_eq!(msg.id.idiag_src[0], addr.sin_addr.s_addr);
+        let mut found_info = false;
+        for a in nlattr_iter(payload) {
+            let (a, data) = a.unwrap();
+            if a.nla_type == c::INET_DIAG_INFO {
+                let info: c::tcp_info = pod_read_init(data).unwrap();
+                assert_eq!(info.tcpi_state, c::TCP_LISTEN);
+                found_info = true;
+            }
+        }
+        assert!(found_info);
+        found = true;
+    });
+    assert!(found);
+}
+
+#[test]
+fn sock_diag_unix() {
+    let name = format!("uapi-sock-diag-{}", getpid());
+    let (addr, len) = sockaddr_un_abstract(name.as_bytes()).unwrap();
+    let server = socket(c::AF_UNIX, c::SOCK_STREAM, 0).unwrap();
+    bind(*server, SockAddrBytes::new(&addr, len).unwrap()).unwrap();
+    listen(*server, 1).unwrap();
+    let ino = fstat(*server).unwrap().st_ino;
+
+    let fd = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_SOCK_DIAG).unwrap();
+    let mut buf = [MaybeUninit::<u8>::uninit(); 1024];
+    let mut writer = NlmsgWriter::new(&mut buf[..], sock_diag_header()).unwrap();
+    let mut req: c::unix_diag_req = pod_zeroed();
+    req.sdiag_family = c::AF_UNIX as _;
+    req.udiag_states = !0;
+    req.udiag_show = c::UDIAG_SHOW_NAME | c::UDIAG_SHOW_RQLEN;
+    writer.write(&req).unwrap();
+
+    let mut found = false;
+    nlmsg_dump(*fd, writer.finalize().unwrap(), |mut payload| {
+        let (_, msg, _) = c::unix_diag_msg::read(&mut payload).unwrap();
+        if msg.udiag_ino as c::ino_t != ino {
+            return;
+        }
+        assert_eq!(msg.udiag_type, c::SOCK_STREAM as u8);
+        assert_eq!(msg.udiag_state, c::TCP_LISTEN);
+        let mut diag_name = None;
+        let mut rqlen = None;
+        for a in nlattr_iter(payload) {
+            let (a, data) = a.unwrap();
+            match a.nla_type {
+                c::UNIX_DIAG_NAME => diag_name = Some(data),
+                c::UNIX_DIAG_RQLEN => {
+                    rqlen = Some(pod_read::<c::unix_diag_rqlen, _>(data).unwrap())
+                }
+                _ => {}
+            }
+        }
+        let diag_name = diag_name.unwrap();
+        assert_eq!(diag_name[0], 0);
+        assert_eq!(&diag_name[1..], name.as_bytes());
+        assert_eq!(rqlen.unwrap().udiag_wqueue, 1);
+        found = true;
+    });
+    assert!(found);
+}
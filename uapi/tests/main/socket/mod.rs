@@ -14,6 +14,7 @@ cfg_if::cfg_if! {
     }
 }
 
+mod addr;
 mod cmsg;
 mod sockopt;
 
@@ -40,3 +40,32 @@ fn linger() {
     getsockopt(*socket, c::SOL_SOCKET, c::SO_LINGER, &mut linger).unwrap();
     assert_eq!(linger.l_onoff, 0);
 }
+
+#[test]
+fn typed() {
+    let socket = socket(c::AF_INET, c::SOCK_STREAM, 0).unwrap();
+
+    assert_eq!(getsockopt_so_type(*socket).unwrap(), c::SOCK_STREAM);
+    assert_eq!(getsockopt_so_acceptconn(*socket).unwrap(), 0);
+    assert_eq!(getsockopt_so_error(*socket).unwrap(), 0);
+
+    setsockopt_so_reuseaddr(*socket, 1).unwrap();
+    assert_ne!(getsockopt_so_reuseaddr(*socket).unwrap(), 0);
+
+    setsockopt_tcp_nodelay(*socket, 1).unwrap();
+    assert_ne!(getsockopt_tcp_nodelay(*socket).unwrap(), 0);
+    setsockopt_tcp_nodelay(*socket, 0).unwrap();
+    assert_eq!(getsockopt_tcp_nodelay(*socket).unwrap(), 0);
+
+    let linger = c::linger {
+        l_onoff: 1,
+        l_linger: 3,
+    };
+    setsockopt_so_linger(*socket, linger).unwrap();
+    let linger = getsockopt_so_linger(*socket).unwrap();
+    assert_ne!(linger.l_onoff, 0);
+    assert_eq!(linger.l_linger, 3);
+
+    setsockopt_so_rcvbuf(*socket, 4096).unwrap();
+    assert!(getsockopt_so_rcvbuf(*socket).unwrap() >= 4096);
+}
@@ -0,0 +1,48 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use std::{process::exit, thread, time::Duration};
+    use testutils::*;
+    use uapi::*;
+
+    #[test_if(linux_5_6)]
+    fn pidfd() {
+        match unsafe { fork().unwrap() } {
+            0 => in_fork(|| loop {
+                thread::sleep(Duration::from_secs(1));
+            }),
+            n => {
+                let pidfd = pidfd_open(n, 0).unwrap();
+                pidfd_send_signal(*pidfd, c::SIGKILL, None, 0).unwrap();
+                let info = waitid(c::P_PIDFD, *pidfd as c::id_t, c::WEXITED).unwrap();
+                unsafe {
+                    assert_eq!(info.si_signo, c::SIGCHLD);
+                    assert_eq!(info.si_code, c::CLD_KILLED);
+                    assert_eq!(info.si_pid(), n);
+                    assert_eq!(info.si_status(), c::SIGKILL);
+                }
+                assert_eq!(
+                    pidfd_send_signal(*pidfd, c::SIGKILL, None, 0).err(),
+                    Some(Errno(c::ESRCH))
+                );
+            }
+        }
+
+        match unsafe { fork().unwrap() } {
+            0 => in_fork(|| exit(4)),
+            n => {
+                let info = waitid(c::P_PID, n as c::id_t, c::WEXITED).unwrap();
+                unsafe {
+                    assert_eq!(info.si_code, c::CLD_EXITED);
+                    assert_eq!(info.si_status(), 4);
+                }
+                assert_eq!(
+                    waitid(c::P_ALL, 0, c::WEXITED | c::WNOHANG).err(),
+                    Some(Errno(c::ECHILD))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use uapi::*;
+
+    #[test]
+    fn name() {
+        prctl_set_name("uapi-prctl").unwrap();
+        let mut buf = [0u8; 16];
+        assert_eq!(prctl_get_name(&mut buf).unwrap().to_bytes(), b"uapi-prctl");
+
+        // truncated by the kernel
+        prctl_set_name("uapi-prctl-0123456789").unwrap();
+        assert_eq!(
+            prctl_get_name(&mut buf).unwrap().to_bytes(),
+            b"uapi-prctl-0123"
+        );
+
+        let mut buf = [0u8; 4];
+        assert_eq!(prctl_get_name(&mut buf).err(), Some(Errno(c::ENAMETOOLONG)));
+    }
+
+    #[test]
+    fn pdeathsig() {
+        prctl_set_pdeathsig(c::SIGUSR1).unwrap();
+        assert_eq!(prctl_get_pdeathsig().unwrap(), c::SIGUSR1);
+        prctl_set_pdeathsig(0).unwrap();
+        assert_eq!(prctl_get_pdeathsig().unwrap(), 0);
+    }
+
+    #[test]
+    fn process_attributes() {
+        prctl_set_child_subreaper(1).unwrap();
+        assert_eq!(prctl_get_child_subreaper().unwrap(), 1);
+        prctl_set_child_subreaper(0).unwrap();
+        assert_eq!(prctl_get_child_subreaper().unwrap(), 0);
+
+        prctl_set_dumpable(0).unwrap();
+        assert_eq!(prctl_get_dumpable().unwrap(), 0);
+        prctl_set_dumpable(1).unwrap();
+        assert_eq!(prctl_get_dumpable().unwrap(), 1);
+        assert_eq!(prctl_set_dumpable(3).err(), Some(Errno(c::EINVAL)));
+
+        prctl_set_keepcaps(1).unwrap();
+        assert_eq!(prctl_get_keepcaps().unwrap(), 1);
+        prctl_set_keepcaps(0).unwrap();
+        assert_eq!(prctl_get_keepcaps().unwrap(), 0);
+    }
+
+    #[test]
+    fn no_new_privs() {
+        prctl_set_no_new_privs().unwrap();
+        assert_eq!(prctl_get_no_new_privs().unwrap(), 1);
+    }
+
+    #[test_if(root)]
+    fn capabilities() {
+        assert_eq!(prctl_capbset_read(c::CAP_CHOWN).unwrap(), 1);
+        prctl_capbset_drop(c::CAP_SYS_BOOT).unwrap();
+        assert_eq!(prctl_capbset_read(c::CAP_SYS_BOOT).unwrap(), 0);
+        assert_eq!(prctl_capbset_read(!0).err(), Some(Errno(c::EINVAL)));
+
+        prctl_cap_ambient_clear_all().unwrap();
+        assert_eq!(prctl_cap_ambient_is_set(c::CAP_CHOWN).unwrap(), 0);
+        prctl_cap_ambient_lower(c::CAP_CHOWN).unwrap();
+    }
+}
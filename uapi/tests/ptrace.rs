@@ -0,0 +1,115 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+    use testutils::*;
+    use uapi::*;
+
+    static EXIT_CODE: AtomicUsize = AtomicUsize::new(0);
+
+    fn wait_stopped(pid: c::pid_t) -> c::c_int {
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFSTOPPED(status));
+        status
+    }
+
+    #[test]
+    fn traceme() {
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            in_fork(|| {
+                ptrace_traceme().unwrap();
+                raise(c::SIGSTOP).unwrap();
+                getppid();
+                unsafe { c::_exit(EXIT_CODE.load(Relaxed) as c::c_int) }
+            });
+        }
+
+        let status = wait_stopped(pid);
+        assert_eq!(WSTOPSIG(status), c::SIGSTOP);
+        assert!(!WIFSYSCALLSTOP(status));
+        assert_eq!(WPTRACEEVENT(status), 0);
+
+        let options =
+            c::PTRACE_O_TRACESYSGOOD | c::PTRACE_O_EXITKILL | c::PTRACE_O_TRACEEXIT;
+        ptrace_setoptions(pid, options).unwrap();
+
+        // fork copies the address space so the static has the same address in the child
+        let addr = &EXIT_CODE as *const _ as usize;
+        assert_eq!(ptrace_peekdata(pid, addr).unwrap(), 0);
+        ptrace_pokedata(pid, addr, 42).unwrap();
+        assert_eq!(ptrace_peekdata(pid, addr).unwrap(), 42);
+        assert_eq!(ptrace_peekdata(pid, 0).err(), Some(Errno(c::EIO)));
+
+        loop {
+            ptrace_syscall(pid, 0).unwrap();
+            let status = wait_stopped(pid);
+            assert!(WIFSYSCALLSTOP(status));
+            let info = ptrace_get_syscall_info(pid).unwrap();
+            if info.op != c::PTRACE_SYSCALL_INFO_ENTRY {
+                continue;
+            }
+            if unsafe { info.u.entry.nr } as c::c_long != c::SYS_getppid {
+                continue;
+            }
+            let mut regs = [0u64; 64];
+            let regs = ptrace_getregset(pid, c::NT_PRSTATUS, &mut regs).unwrap();
+            assert_eq!(regs.len(), std::mem::size_of::<c::user_regs_struct>());
+            #[cfg(target_arch = "x86_64")]
+            {
+                // orig_rax is the 16th register
+                let orig_rax: u64 = pod_read(&regs[15 * 8..16 * 8]).unwrap();
+                assert_eq!(orig_rax as c::c_long, c::SYS_getppid);
+            }
+            break;
+        }
+
+        ptrace_cont(pid, 0).unwrap();
+        let status = wait_stopped(pid);
+        assert_eq!(WPTRACEEVENT(status), c::PTRACE_EVENT_EXIT);
+        assert!(!WIFSYSCALLSTOP(status));
+        let msg = ptrace_geteventmsg(pid).unwrap() as c::c_int;
+        assert!(WIFEXITED(msg));
+        assert_eq!(WEXITSTATUS(msg), 42);
+
+        ptrace_cont(pid, 0).unwrap();
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFEXITED(status));
+        assert_eq!(WEXITSTATUS(status), 42);
+    }
+
+    #[test]
+    fn seize() {
+        let (ready_r, _ready_w) = pipe().unwrap();
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            in_fork(|| {
+                let mut buf = [0u8; 1];
+                read(*ready_r, &mut buf[..]).unwrap();
+            });
+        }
+        ptrace_seize(pid, c::PTRACE_O_EXITKILL).unwrap();
+        ptrace_interrupt(pid).unwrap();
+        let status = wait_stopped(pid);
+        assert_eq!(WPTRACEEVENT(status), c::PTRACE_EVENT_STOP);
+        assert_eq!(WSTOPSIG(status), c::SIGTRAP);
+        assert!(!WIFGROUPSTOP(status));
+
+        kill(pid, c::SIGSTOP).unwrap();
+        ptrace_cont(pid, 0).unwrap();
+        // signal-delivery-stop followed by a group-stop after the signal is injected
+        let status = wait_stopped(pid);
+        assert_eq!(WSTOPSIG(status), c::SIGSTOP);
+        assert_eq!(WPTRACEEVENT(status), 0);
+        ptrace_cont(pid, c::SIGSTOP).unwrap();
+        let status = wait_stopped(pid);
+        assert!(WIFGROUPSTOP(status));
+
+        ptrace_detach(pid, 0).unwrap();
+        kill(pid, c::SIGKILL).unwrap();
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFSIGNALED(status));
+        assert_eq!(WTERMSIG(status), c::SIGKILL);
+    }
+}
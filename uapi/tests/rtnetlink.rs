@@ -0,0 +1,256 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+#[allow(deprecated)]
+mod wrapper {
+    use proc::*;
+    use std::mem::MaybeUninit;
+    use testutils::*;
+    use uapi::*;
+
+    fn header(ty: u16, flags: c::c_int) -> c::nlmsghdr {
+        c::nlmsghdr {
+            nlmsg_len: 0,
+            nlmsg_type: ty,
+            nlmsg_flags: (c::NLM_F_REQUEST | flags) as _,
+            nlmsg_seq: 0,
+            nlmsg_pid: 0,
+        }
+    }
+
+    fn attr(ty: c::c_ushort) -> c::nlattr {
+        c::nlattr {
+            nla_len: 0,
+            nla_type: ty,
+        }
+    }
+
+    fn rtattr(ty: c::c_ushort) -> c::rtattr {
+        c::rtattr {
+            rta_len: 0,
+            rta_type: ty,
+        }
+    }
+
+    fn request(fd: c::c_int, msg: &[u8]) {
+        send(fd, msg, 0).unwrap();
+        let mut buf = [0u8; 8192];
+        let reader = &*recv(fd, &mut buf[..], 0).unwrap();
+        let (header, payload) = nlmsg_iter(reader).next().unwrap().unwrap();
+        let err = nlmsg_read_error(&header, payload).unwrap();
+        assert_eq!(err.error, 0, "{}", Errno(-err.error));
+    }
+
+    fn if_index(fd: c::c_int, name: &[u8]) -> i32 {
+        let mut buf = [MaybeUninit::<u8>::uninit(); 1024];
+        let mut writer =
+            NlmsgWriter::new(&mut buf[..], header(c::RTM_GETLINK, c::NLM_F_DUMP))
+                .unwrap();
+        writer.write(&pod_zeroed::<c::ifinfomsg>()).unwrap();
+        let msg = writer.finalize().unwrap();
+        let mut index = None;
+        nlmsg_dump(fd, msg, |mut payload| {
+            let (_, ifi, _) = c::ifinfomsg::read(&mut payload).unwrap();
+            for a in rtattr_iter(payload) {
+                let (a, data) = a.unwrap();
+                if a.rta_type == c::IFLA_IFNAME && data.strip_suffix(b"\0") == Some(name)
+                {
+                    index = Some(ifi.ifi_index);
+                }
+            }
+        });
+        index.unwrap()
+    }
+
+    #[test_if(root)]
+    fn veth() {
+        unshare(c::CLONE_NEWNET).unwrap();
+        let fd = socket(c::AF_NETLINK, c::SOCK_RAW, c::NETLINK_ROUTE).unwrap();
+        let fd = *fd;
+        let mut buf = [MaybeUninit::<u8>::uninit(); 1024];
+
+        // create a veth pair
+        {
+            let mut writer = NlmsgWriter::new(
+                &mut buf[..],
+                header(
+                    c::RTM_NEWLINK,
+                    c::NLM_F_ACK | c::NLM_F_CREATE | c::NLM_F_EXCL,
+                ),
+            )
+            .unwrap();
+            writer.write(&pod_zeroed::<c::ifinfomsg>()).unwrap();
+            writer
+                .nest(attr(c::IFLA_IFNAME))
+                .unwrap()
+                .write(b"uapi0\0")
+                .unwrap();
+            {
+                let mut linkinfo = writer.nest(attr(c::IFLA_LINKINFO)).unwrap();
+                linkinfo
+                    .nest(attr(c::IFLA_INFO_KIND))
+                    .unwrap()
+                    .write(b"veth")
+                    .unwrap();
+                let mut data = linkinfo.nest(attr(c::IFLA_INFO_DATA)).unwrap();
+                let mut peer = data.nest(attr(c::VETH_INFO_PEER)).unwrap();
+                peer.write(&pod_zeroed::<c::ifinfomsg>()).unwrap();
+                peer.nest(attr(c::IFLA_IFNAME))
+                    .unwrap()
+                    .write(b"uapi1\0")
+                    .unwrap();
+            }
+            request(fd, writer.finalize().unwrap());
+        }
+        let index = if_index(fd, b"uapi0");
+        assert_ne!(if_index(fd, b"uapi1"), index);
+
+        // bring it up
+        {
+            let mut writer =
+                NlmsgWriter::new(&mut buf[..], header(c::RTM_NEWLINK, c::NLM_F_ACK))
+                    .unwrap();
+            let mut ifi: c::ifinfomsg = pod_zeroed();
+            ifi.ifi_index = index;
+            ifi.ifi_flags = c::IFF_UP as _;
+            ifi.ifi_change = c::IFF_UP as _;
+            writer.write(&ifi).unwrap();
+            request(fd, writer.finalize().unwrap());
+        }
+
+        // add 10.1.2.3/24
+        let local = [10u8, 1, 2, 3];
+        {
+            let mut writer = NlmsgWriter::new(
+                &mut buf[..],
+                header(
+                    c::RTM_NEWADDR,
+                    c::NLM_F_ACK | c::NLM_F_CREATE | c::NLM_F_EXCL,
+                ),
+            )
+            .unwrap();
+            writer
+                .write(&c::ifaddrmsg {
+                    ifa_family: c::AF_INET as _,
+                    ifa_prefixlen: 24,
+                    ifa_flags: 0,
+                    ifa_scope: c::RT_SCOPE_UNIVERSE,
+                    ifa_index: index as _,
+                })
+                .unwrap();
+            writer
+                .nest(rtattr(c::IFA_LOCAL))
+                .unwrap()
+                .write(&local)
+                .unwrap();
+            writer
+                .nest(rtattr(c::IFA_ADDRESS))
+                .unwrap()
+                .write(&local)
+                .unwrap();
+            request(fd, writer.finalize().unwrap());
+        }
+
+        // add 10.9.0.0/16 via 10.1.2.4
+        let dst = [10u8, 9, 0, 0];
+        let gateway = [10u8, 1, 2, 4];
+        {
+            let mut writer = NlmsgWriter::new(
+                &mut buf[..],
+                header(
+                    c::RTM_NEWROUTE,
+                    c::NLM_F_ACK | c::NLM_F_CREATE | c::NLM_F_EXCL,
+                ),
+            )
+            .unwrap();
+            writer
+                .write(&c::rtmsg {
+                    rtm_family: c::AF_INET as _,
+                    rtm_dst_len: 16,
+                    rtm_src_len: 0,
+                    rtm_tos: 0,
+                    rtm_table: c::RT_TABLE_MAIN,
+                    rtm_protocol: c::RTPROT_STATIC,
+                    rtm_scope: c::RT_SCOPE_UNIVERSE,
+                    rtm_type: c::RTN_UNICAST,
+                    rtm_flags: 0,
+                })
+                .unwrap();
+            writer
+                .nest(rtattr(c::RTA_DST))
+                .unwrap()
+                .write(&dst)
+                .unwrap();
+            writer
+                .nest(rtattr(c::RTA_GATEWAY))
+                .unwrap()
+                .write(&gateway)
+                .unwrap();
+            writer
+                .nest(rtattr(c::RTA_OIF))
+                .unwrap()
+                .write(&index)
+                .unwrap();
+            request(fd, writer.finalize().unwrap());
+        }
+
+        // read back the address
+        let mut found_addr = false;
+        {
+            let mut writer =
+                NlmsgWriter::new(&mut buf[..], header(c::RTM_GETADDR, c::NLM_F_DUMP))
+                    .unwrap();
+            let mut ifa: c::ifaddrmsg = pod_zeroed();
+            ifa.ifa_family = c::AF_INET as _;
+            writer.write(&ifa).unwrap();
+            nlmsg_dump(fd, writer.finalize().unwrap(), |mut payload| {
+                let (_, ifa, _) = c::ifaddrmsg::read(&mut payload).unwrap();
+                if ifa.ifa_index != index as u32 {
+                    return;
+                }
+                assert_eq!(ifa.ifa_prefixlen, 24);
+                for a in rtattr_iter(payload) {
+                    let (a, data) = a.unwrap();
+                    if a.rta_type == c::IFA_LOCAL {
+                        assert_eq!(data, local);
+                        found_addr = true;
+                    }
+                }
+            });
+        }
+        assert!(found_addr);
+
+        // read back the route
+        let mut found_route = false;
+        {
+            let mut writer =
+                NlmsgWriter::new(&mut buf[..], header(c::RTM_GETROUTE, c::NLM_F_DUMP))
+                    .unwrap();
+            let mut rtm: c::rtmsg = pod_zeroed();
+            rtm.rtm_family = c::AF_INET as _;
+            writer.write(&rtm).unwrap();
+            nlmsg_dump(fd, writer.finalize().unwrap(), |mut payload| {
+                let (_, rtm, _) = c::rtmsg::read(&mut payload).unwrap();
+                if rtm.rtm_table != c::RT_TABLE_MAIN || rtm.rtm_dst_len != 16 {
+                    return;
+                }
+                let mut route_dst = None;
+                let mut route_gateway = None;
+                for a in rtattr_iter(payload) {
+                    let (a, data) = a.unwrap();
+                    match a.rta_type {
+                        c::RTA_DST => route_dst = Some(data),
+                        c::RTA_GATEWAY => route_gateway = Some(data),
+                        _ => {}
+                    }
+                }
+                if route_dst == Some(&dst[..]) {
+                    assert_eq!(rtm.rtm_protocol, c::RTPROT_STATIC);
+                    assert_eq!(route_gateway, Some(&gateway[..]));
+                    found_route = true;
+                }
+            });
+        }
+        assert!(found_route);
+    }
+}
@@ -0,0 +1,107 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use std::{
+        io::{IoSlice, IoSliceMut},
+        mem::{self, MaybeUninit},
+    };
+    use testutils::*;
+    use uapi::*;
+
+    fn filter() -> Vec<c::sock_filter> {
+        let mut b = BpfBuilder::new();
+        let allow = b.label();
+        b.ld_abs(c::BPF_W, 0)
+            .jeq(c::SYS_getppid as u32, None, Some(allow))
+            .ret(c::SECCOMP_RET_USER_NOTIF)
+            .bind(allow)
+            .ret(c::SECCOMP_RET_ALLOW);
+        b.finish().unwrap()
+    }
+
+    #[test_if(linux_5_9)]
+    fn user_notif() {
+        seccomp_get_action_avail(c::SECCOMP_RET_USER_NOTIF).unwrap();
+        let sizes = seccomp_get_notif_sizes().unwrap();
+        assert!(sizes.seccomp_notif as usize >= mem::size_of::<c::seccomp_notif>());
+        assert!(
+            sizes.seccomp_notif_resp as usize >= mem::size_of::<c::seccomp_notif_resp>()
+        );
+        assert!(sizes.seccomp_data as usize >= mem::size_of::<c::seccomp_data>());
+
+        let (parent, child) = socketpair(c::AF_UNIX, c::SOCK_DGRAM, 0).unwrap();
+
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            in_fork(|| {
+                unsafe {
+                    assert_eq!(c::prctl(c::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0), 0);
+                }
+                let listener = seccomp_set_mode_filter_listener(0, &filter()).unwrap();
+
+                let mut ctrl = [MaybeUninit::uninit(); 128];
+                let len =
+                    cmsg_write_scm_rights(&mut &mut ctrl[..], &[*listener]).unwrap();
+                let msghdr = Msghdr {
+                    iov: &[IoSlice::new(b"x")][..],
+                    control: Some(&ctrl[..len]),
+                    name: sockaddr_none_ref(),
+                };
+                sendmsg(*child, &msghdr, 0).unwrap();
+                drop(listener);
+
+                // the supervisor installs a file descriptor and returns its number
+                let fd = getppid();
+                write(fd, b"ok").unwrap();
+            });
+        }
+
+        let mut data = [0u8; 1];
+        let mut buf = [0u8; 128];
+        let mut msghdr = MsghdrMut {
+            iov: &mut [IoSliceMut::new(&mut data)][..],
+            control: Some(&mut buf[..]),
+            name: sockaddr_none_mut(),
+            flags: 0,
+        };
+        let (_, _, ctrl) = recvmsg(*parent, &mut msghdr, 0).unwrap();
+        let (_, data) = cmsg_iter(ctrl).next().unwrap().unwrap();
+        let listener = cmsg_read_scm_rights(data).unwrap().next().unwrap();
+
+        let notif = seccomp_ioctl_notif_recv(*listener).unwrap();
+        assert_eq!(notif.pid as c::pid_t, pid);
+        assert_eq!(notif.data.nr as c::c_long, c::SYS_getppid);
+        seccomp_ioctl_notif_id_valid(*listener, notif.id).unwrap();
+
+        let (pipe_r, pipe_w) = pipe().unwrap();
+        let addfd = c::seccomp_notif_addfd {
+            id: notif.id,
+            flags: 0,
+            srcfd: *pipe_w as _,
+            newfd: 0,
+            newfd_flags: 0,
+        };
+        let fd = seccomp_ioctl_notif_addfd(*listener, &addfd).unwrap();
+        drop(pipe_w);
+        let resp = c::seccomp_notif_resp {
+            id: notif.id,
+            val: fd as _,
+            error: 0,
+            flags: 0,
+        };
+        seccomp_ioctl_notif_send(*listener, &resp).unwrap();
+
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFEXITED(status));
+        assert_eq!(WEXITSTATUS(status), 0);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(read(*pipe_r, &mut buf[..]).unwrap(), b"ok");
+        assert_eq!(
+            seccomp_ioctl_notif_id_valid(*listener, notif.id).err(),
+            Some(Errno(c::ENOENT))
+        );
+    }
+}
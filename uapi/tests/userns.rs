@@ -0,0 +1,114 @@
+extern crate proc; // https://github.com/rust-lang/rust/issues/64450
+
+#[cfg(target_os = "linux")]
+mod wrapper {
+    use proc::*;
+    use testutils::*;
+    use uapi::*;
+
+    fn read_to_string(dir: c::c_int, name: &str) -> String {
+        let fd = openat(dir, name, c::O_RDONLY | c::O_CLOEXEC, 0).unwrap();
+        let mut buf = [0u8; 1024];
+        String::from_utf8(read(*fd, &mut buf[..]).unwrap().to_vec()).unwrap()
+    }
+
+    fn fields(s: &str) -> Vec<Vec<u32>> {
+        s.lines()
+            .map(|l| l.split_whitespace().map(|f| f.parse().unwrap()).collect())
+            .collect()
+    }
+
+    #[test_if(root)]
+    fn self_maps() {
+        let uid = getuid();
+        let gid = getgid();
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            in_fork(|| {
+                unshare(c::CLONE_NEWUSER).unwrap();
+                let dir = open("/proc/self", c::O_DIRECTORY | c::O_CLOEXEC, 0).unwrap();
+                let uid_map = [IdMapRange {
+                    inside: 0,
+                    outside: uid,
+                    count: 1,
+                }];
+                let gid_map = [IdMapRange {
+                    inside: 0,
+                    outside: gid,
+                    count: 1,
+                }];
+                proc_write_id_maps(*dir, &uid_map, &gid_map, true).unwrap();
+                assert_eq!(read_to_string(*dir, "setgroups"), "deny\n");
+                assert_eq!(getuid(), 0);
+                assert_eq!(getgid(), 0);
+
+                // the maps can only be written once
+                assert_eq!(
+                    proc_write_uid_map(*dir, &uid_map).err(),
+                    Some(Errno(c::EPERM))
+                );
+                // setgroups cannot be allowed again
+                assert_eq!(
+                    proc_write_setgroups(*dir, true).err(),
+                    Some(Errno(c::EPERM))
+                );
+            });
+        }
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFEXITED(status));
+        assert_eq!(WEXITSTATUS(status), 0);
+    }
+
+    #[test_if(root)]
+    fn pidfd_maps() {
+        let (ready_r, ready_w) = pipe().unwrap();
+        let (done_r, done_w) = pipe().unwrap();
+        let pid = unsafe { fork().unwrap() };
+        if pid == 0 {
+            in_fork(|| {
+                unshare(c::CLONE_NEWUSER).unwrap();
+                write(*ready_w, b"x").unwrap();
+                let mut buf = [0u8; 1];
+                read(*done_r, &mut buf[..]).unwrap();
+                assert_eq!(getuid(), 1);
+            });
+        }
+        drop(ready_w);
+        drop(done_r);
+        let mut buf = [0u8; 1];
+        read(*ready_r, &mut buf[..]).unwrap();
+
+        let pidfd = pidfd_open(pid, 0).unwrap();
+        let dir = pidfd_open_proc_dir(*pidfd).unwrap();
+        let uid_map = [
+            IdMapRange {
+                inside: 0,
+                outside: 100000,
+                count: 1,
+            },
+            IdMapRange {
+                inside: 1,
+                outside: getuid(),
+                count: 1,
+            },
+        ];
+        let overlapping = [uid_map[0], uid_map[0]];
+        assert_eq!(
+            proc_write_uid_map(*dir, &overlapping).err(),
+            Some(Errno(c::EINVAL))
+        );
+        proc_write_uid_map(*dir, &uid_map).unwrap();
+        assert_eq!(
+            fields(&read_to_string(*dir, "uid_map")),
+            [vec![0, 100000, 1], vec![1, getuid(), 1]]
+        );
+
+        write(*done_w, b"x").unwrap();
+        let (_, status) = waitpid(pid, 0).unwrap();
+        assert!(WIFEXITED(status));
+        assert_eq!(WEXITSTATUS(status), 0);
+
+        assert_eq!(pidfd_open_proc_dir(*pidfd).err(), Some(Errno(c::ESRCH)));
+        assert_eq!(pidfd_open_proc_dir(*dir).err(), Some(Errno(c::EINVAL)));
+    }
+}